@@ -8,9 +8,15 @@
 //! - Single-pass duration aggregation
 //! - Cache-friendly memory layout
 
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 use wasm_bindgen::prelude::*;
 use gastown_shared::{FxHashMap, pool::SmallBuffer};
-use crate::{BeadNode, CriticalPathResult};
+use crate::{BeadNode, CriticalPathResult, NearCriticalPath, ResourceScheduleResult};
+
+/// Default cap on the number of near-critical paths returned when the caller
+/// doesn't supply one, to avoid exponential blowup on diamond-heavy graphs.
+const DEFAULT_MAX_NEAR_CRITICAL_PATHS: usize = 100;
 
 /// Compute critical path through bead dependencies
 ///
@@ -237,6 +243,280 @@ fn build_critical_path_optimized(
     path
 }
 
+/// Schedule beads onto a fixed number of workers using list scheduling
+///
+/// Priority rule: smallest slack first (from the unlimited-parallelism CPM
+/// pass), tie-broken by descending `priority`.
+#[inline]
+pub fn schedule_resource_constrained_impl(beads_json: &str, num_workers: u32) -> Result<String, JsValue> {
+    let beads: Vec<BeadNode> = serde_json::from_str(beads_json)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+    let result = schedule_resource_constrained_internal(&beads, num_workers.max(1) as usize)?;
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Serialize error: {}", e)))
+}
+
+/// Internal list-scheduling simulation bounded to `num_workers` concurrent beads
+fn schedule_resource_constrained_internal(beads: &[BeadNode], num_workers: usize) -> Result<ResourceScheduleResult, JsValue> {
+    if beads.is_empty() {
+        return Ok(ResourceScheduleResult {
+            starts: FxHashMap::default(),
+            finishes: FxHashMap::default(),
+            makespan: 0,
+        });
+    }
+
+    let n = beads.len();
+
+    let mut id_to_index: FxHashMap<&str, usize> = FxHashMap::default();
+    id_to_index.reserve(n);
+    for (i, bead) in beads.iter().enumerate() {
+        id_to_index.insert(&bead.id, i);
+    }
+
+    let durations: Vec<u32> = beads.iter().map(|b| b.duration.unwrap_or(1)).collect();
+
+    // Reuse the unlimited-parallelism CPM pass to get per-bead slack
+    let cpm = critical_path_internal(beads)?;
+
+    let mut remaining: Vec<usize> = beads.iter().map(|b| b.blocked_by.len()).collect();
+    let mut successors: Vec<SmallBuffer<usize, 8>> = vec![SmallBuffer::new(); n];
+    for (i, bead) in beads.iter().enumerate() {
+        for blocked in &bead.blocks {
+            if let Some(&j) = id_to_index.get(blocked.as_str()) {
+                successors[i].push(j);
+            }
+        }
+    }
+
+    let slack_of = |i: usize| -> u32 { *cpm.slack.get(&beads[i].id).unwrap_or(&0) };
+
+    let mut ready: Vec<usize> = (0..n).filter(|&i| remaining[i] == 0).collect();
+    let mut busy: BinaryHeap<Reverse<(u32, usize)>> = BinaryHeap::new();
+    let mut idle_workers = num_workers;
+    let mut clock: u32 = 0;
+    let mut scheduled = 0usize;
+
+    let mut starts: FxHashMap<String, u32> = FxHashMap::default();
+    let mut finishes: FxHashMap<String, u32> = FxHashMap::default();
+    starts.reserve(n);
+    finishes.reserve(n);
+
+    while scheduled < n {
+        // Smallest slack first, ties broken by descending priority
+        ready.sort_by(|&a, &b| {
+            slack_of(a).cmp(&slack_of(b)).then(beads[b].priority.cmp(&beads[a].priority))
+        });
+
+        while idle_workers > 0 && !ready.is_empty() {
+            let i = ready.remove(0);
+            idle_workers -= 1;
+
+            let start = clock;
+            let finish = start + durations[i];
+            starts.insert(beads[i].id.clone(), start);
+            finishes.insert(beads[i].id.clone(), finish);
+            busy.push(Reverse((finish, i)));
+            scheduled += 1;
+        }
+
+        if scheduled == n {
+            break;
+        }
+
+        // No idle worker or nothing ready yet: advance the clock to the next
+        // finishing bead and free its worker (and any others finishing at the
+        // same instant, including duration-0 beads that finish instantly).
+        if let Some(&Reverse((next_finish, _))) = busy.peek() {
+            clock = clock.max(next_finish);
+            while let Some(&Reverse((f, i))) = busy.peek() {
+                if f > clock {
+                    break;
+                }
+                busy.pop();
+                idle_workers += 1;
+                for &s in &successors[i] {
+                    remaining[s] -= 1;
+                    if remaining[s] == 0 {
+                        ready.push(s);
+                    }
+                }
+            }
+        } else {
+            // Nothing busy and nothing ready: dependency data is inconsistent
+            // (e.g. a blocker id that doesn't resolve to a bead). Bail rather
+            // than spin forever.
+            break;
+        }
+    }
+
+    let makespan = finishes.values().copied().max().unwrap_or(0);
+
+    Ok(ResourceScheduleResult {
+        starts,
+        finishes,
+        makespan,
+    })
+}
+
+/// Enumerate every maximal path whose every node has slack <= `slack_threshold`
+///
+/// Paths are sorted by total duration descending; paths that are a contiguous
+/// subpath of a longer returned path are dropped.
+#[inline]
+pub fn critical_paths_impl(beads_json: &str, slack_threshold: u32, max_paths: usize) -> Result<String, JsValue> {
+    let beads: Vec<BeadNode> = serde_json::from_str(beads_json)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+    let max_paths = if max_paths == 0 { DEFAULT_MAX_NEAR_CRITICAL_PATHS } else { max_paths };
+    let result = critical_paths_internal(&beads, slack_threshold, max_paths)?;
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Serialize error: {}", e)))
+}
+
+/// Internal near-critical path enumeration
+fn critical_paths_internal(beads: &[BeadNode], slack_threshold: u32, max_paths: usize) -> Result<Vec<NearCriticalPath>, JsValue> {
+    if beads.is_empty() {
+        return Ok(vec![]);
+    }
+
+    let n = beads.len();
+
+    let mut id_to_index: FxHashMap<&str, usize> = FxHashMap::default();
+    id_to_index.reserve(n);
+    for (i, bead) in beads.iter().enumerate() {
+        id_to_index.insert(&bead.id, i);
+    }
+
+    let durations: Vec<u32> = beads.iter().map(|b| b.duration.unwrap_or(1)).collect();
+    let topo_order = topo_sort_kahn_indices(beads, &id_to_index)?;
+
+    // Forward pass: earliest start/finish
+    let mut earliest_start: Vec<u32> = vec![0; n];
+    let mut earliest_finish: Vec<u32> = vec![0; n];
+    for &i in &topo_order {
+        let bead = &beads[i];
+        let es = bead.blocked_by.iter()
+            .filter_map(|dep| id_to_index.get(dep.as_str()))
+            .map(|&j| earliest_finish[j])
+            .max()
+            .unwrap_or(0);
+        earliest_start[i] = es;
+        earliest_finish[i] = es + durations[i];
+    }
+
+    let project_duration = earliest_finish.iter().max().copied().unwrap_or(0);
+
+    // Backward pass: latest start/finish, using the full successor graph
+    let mut successors: Vec<SmallBuffer<usize, 8>> = vec![SmallBuffer::new(); n];
+    for (i, bead) in beads.iter().enumerate() {
+        for blocked in &bead.blocks {
+            if let Some(&j) = id_to_index.get(blocked.as_str()) {
+                successors[i].push(j);
+            }
+        }
+    }
+
+    let mut latest_start: Vec<u32> = vec![0; n];
+    for &i in topo_order.iter().rev() {
+        let lf = successors[i].iter()
+            .map(|&j| latest_start[j])
+            .min()
+            .unwrap_or(project_duration);
+        latest_start[i] = lf.saturating_sub(durations[i]);
+    }
+
+    let slack: Vec<u32> = (0..n).map(|i| latest_start[i].saturating_sub(earliest_start[i])).collect();
+    let in_threshold: Vec<bool> = slack.iter().map(|&s| s <= slack_threshold).collect();
+
+    // Restrict the successor/predecessor graph to nodes under the threshold
+    let mut succ_thresh: Vec<SmallBuffer<usize, 8>> = vec![SmallBuffer::new(); n];
+    let mut has_pred_in_thresh: Vec<bool> = vec![false; n];
+    for i in 0..n {
+        if !in_threshold[i] {
+            continue;
+        }
+        for &j in &successors[i] {
+            if in_threshold[j] {
+                succ_thresh[i].push(j);
+                has_pred_in_thresh[j] = true;
+            }
+        }
+    }
+
+    let sources: Vec<usize> = (0..n).filter(|&i| in_threshold[i] && !has_pred_in_thresh[i]).collect();
+
+    let mut paths: Vec<Vec<usize>> = Vec::new();
+    let mut current: Vec<usize> = Vec::new();
+    for &s in &sources {
+        if paths.len() >= max_paths {
+            break;
+        }
+        enumerate_paths(s, &succ_thresh, &mut current, &mut paths, max_paths);
+    }
+
+    let mut results: Vec<NearCriticalPath> = paths.iter().map(|p| NearCriticalPath {
+        path: p.iter().map(|&i| beads[i].id.clone()).collect(),
+        duration: p.iter().map(|&i| durations[i]).sum(),
+        min_slack: p.iter().map(|&i| slack[i]).min().unwrap_or(0),
+    }).collect();
+
+    results.sort_by(|a, b| b.duration.cmp(&a.duration));
+
+    // Drop paths that are a contiguous subpath of an already-kept longer path
+    let mut deduped: Vec<NearCriticalPath> = Vec::new();
+    'candidates: for cand in results {
+        for kept in &deduped {
+            if kept.path.len() > cand.path.len() && is_subpath(&kept.path, &cand.path) {
+                continue 'candidates;
+            }
+        }
+        deduped.push(cand);
+    }
+
+    Ok(deduped)
+}
+
+/// DFS over the slack-restricted subgraph, emitting one path per sink reached
+fn enumerate_paths(
+    node: usize,
+    succ: &[SmallBuffer<usize, 8>],
+    current: &mut Vec<usize>,
+    paths: &mut Vec<Vec<usize>>,
+    max_paths: usize,
+) {
+    if paths.len() >= max_paths {
+        return;
+    }
+
+    current.push(node);
+
+    if succ[node].is_empty() {
+        paths.push(current.clone());
+    } else {
+        for &next in &succ[node] {
+            if paths.len() >= max_paths {
+                break;
+            }
+            enumerate_paths(next, succ, current, paths, max_paths);
+        }
+    }
+
+    current.pop();
+}
+
+/// True if `needle` appears as a contiguous run within `haystack`
+#[inline]
+fn is_subpath(haystack: &[String], needle: &[String]) -> bool {
+    if needle.is_empty() || needle.len() > haystack.len() {
+        return false;
+    }
+    haystack.windows(needle.len()).any(|w| w == needle)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -362,4 +642,116 @@ mod tests {
         assert_eq!(result.path[0], "only");
         assert_eq!(result.slack.get("only"), Some(&0));
     }
+
+    fn create_parallel_beads() -> Vec<BeadNode> {
+        // a, b, c are all ready at once; d depends on all three.
+        vec!["a", "b", "c"].into_iter().map(|id| BeadNode {
+            id: id.to_string(),
+            title: id.to_string(),
+            status: "open".to_string(),
+            priority: 0,
+            blocked_by: vec![],
+            blocks: vec!["d".to_string()],
+            duration: Some(10),
+        }).chain(std::iter::once(BeadNode {
+            id: "d".to_string(),
+            title: "D".to_string(),
+            status: "open".to_string(),
+            priority: 0,
+            blocked_by: vec!["a".to_string(), "b".to_string(), "c".to_string()],
+            blocks: vec![],
+            duration: Some(5),
+        })).collect()
+    }
+
+    #[test]
+    fn test_schedule_unlimited_workers_runs_everything_in_parallel() {
+        let beads = create_parallel_beads();
+        let result = schedule_resource_constrained_internal(&beads, 3).unwrap();
+
+        assert_eq!(result.starts.get("a"), Some(&0));
+        assert_eq!(result.starts.get("b"), Some(&0));
+        assert_eq!(result.starts.get("c"), Some(&0));
+        assert_eq!(result.starts.get("d"), Some(&10));
+        assert_eq!(result.makespan, 15);
+    }
+
+    #[test]
+    fn test_schedule_single_worker_serializes_ready_beads() {
+        let beads = create_parallel_beads();
+        let result = schedule_resource_constrained_internal(&beads, 1).unwrap();
+
+        // Only one bead can run at a time, so a/b/c are serialized before d.
+        assert_eq!(result.makespan, 35);
+        assert_eq!(result.starts.get("d"), Some(&30));
+    }
+
+    #[test]
+    fn test_schedule_zero_duration_finishes_instantly() {
+        let beads = vec![BeadNode {
+            id: "instant".to_string(),
+            title: "Instant".to_string(),
+            status: "open".to_string(),
+            priority: 0,
+            blocked_by: vec![],
+            blocks: vec![],
+            duration: Some(0),
+        }];
+
+        let result = schedule_resource_constrained_internal(&beads, 1).unwrap();
+        assert_eq!(result.starts.get("instant"), Some(&0));
+        assert_eq!(result.finishes.get("instant"), Some(&0));
+        assert_eq!(result.makespan, 0);
+    }
+
+    #[test]
+    fn test_near_critical_paths_includes_secondary_chain() {
+        // a (10) -> c (5): critical, 15 total, 0 slack
+        // b (8)  -> c (5): near-critical, 13 total, 2 slack
+        let beads = vec![
+            BeadNode {
+                id: "a".to_string(),
+                title: "A".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec![],
+                blocks: vec!["c".to_string()],
+                duration: Some(10),
+            },
+            BeadNode {
+                id: "b".to_string(),
+                title: "B".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec![],
+                blocks: vec!["c".to_string()],
+                duration: Some(8),
+            },
+            BeadNode {
+                id: "c".to_string(),
+                title: "C".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec!["a".to_string(), "b".to_string()],
+                blocks: vec![],
+                duration: Some(5),
+            },
+        ];
+
+        let tight = critical_paths_internal(&beads, 0, 10).unwrap();
+        assert_eq!(tight.len(), 1);
+        assert_eq!(tight[0].path, vec!["a".to_string(), "c".to_string()]);
+
+        let loose = critical_paths_internal(&beads, 2, 10).unwrap();
+        assert_eq!(loose.len(), 2);
+        assert_eq!(loose[0].path, vec!["a".to_string(), "c".to_string()]);
+        assert_eq!(loose[1].path, vec!["b".to_string(), "c".to_string()]);
+    }
+
+    #[test]
+    fn test_near_critical_paths_caps_output() {
+        let beads = create_parallel_beads();
+        let result = critical_paths_internal(&beads, 1000, 1).unwrap();
+        assert_eq!(result.len(), 1);
+    }
 }
@@ -12,8 +12,8 @@
 use wasm_bindgen::prelude::*;
 use petgraph::graph::{DiGraph, NodeIndex};
 use petgraph::algo::is_cyclic_directed;
-use gastown_shared::{FxHashMap, FxHashSet, pool::SmallBuffer, capacity};
-use crate::BeadNode;
+use gastown_shared::{fx_hash_str, FxHashMap, FxHashSet, pool::SmallBuffer, capacity};
+use crate::{BeadNode, BeadSchedule, CyclesResult, ScheduleAnalysisResult};
 
 /// Check if the dependency graph has cycles
 ///
@@ -249,6 +249,774 @@ fn find_cycle_nodes_internal(beads: &[BeadNode]) -> Vec<String> {
     cycle_nodes
 }
 
+/// Report every non-trivial strongly-connected component plus one concrete
+/// cycle per component, instead of a flat bag of node ids
+#[inline]
+pub fn find_cycles_impl(beads_json: &str) -> Result<String, JsValue> {
+    let beads: Vec<BeadNode> = serde_json::from_str(beads_json)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+    let result = find_cycles_internal(&beads);
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Serialize error: {}", e)))
+}
+
+/// Find cycle groups via an iterative (stack-safe) Tarjan SCC pass, plus one
+/// example closed walk per group reconstructed by DFS within the component
+fn find_cycles_internal(beads: &[BeadNode]) -> CyclesResult {
+    let n = beads.len();
+    if n == 0 {
+        return CyclesResult { components: vec![], example_cycles: vec![] };
+    }
+
+    let mut id_to_index: FxHashMap<&str, usize> = FxHashMap::default();
+    id_to_index.reserve(n);
+    for (i, bead) in beads.iter().enumerate() {
+        id_to_index.insert(&bead.id, i);
+    }
+
+    let mut adj: Vec<SmallBuffer<usize, 8>> = vec![SmallBuffer::new(); n];
+    for bead in beads {
+        if let Some(&from_idx) = id_to_index.get(bead.id.as_str()) {
+            for blocked in &bead.blocks {
+                if let Some(&to_idx) = id_to_index.get(blocked.as_str()) {
+                    adj[from_idx].push(to_idx);
+                }
+            }
+        }
+    }
+
+    let sccs = tarjan_scc_iterative(n, &adj);
+
+    let mut components: Vec<Vec<String>> = Vec::new();
+    let mut example_cycles: Vec<Vec<String>> = Vec::new();
+
+    for scc in &sccs {
+        let is_self_loop = scc.len() == 1 && adj[scc[0]].contains(&scc[0]);
+        if scc.len() <= 1 && !is_self_loop {
+            continue;
+        }
+
+        components.push(scc.iter().map(|&i| beads[i].id.clone()).collect());
+        if let Some(cycle) = reconstruct_cycle(scc, &adj) {
+            example_cycles.push(cycle.iter().map(|&i| beads[i].id.clone()).collect());
+        }
+    }
+
+    CyclesResult { components, example_cycles }
+}
+
+/// Tarjan's SCC algorithm using an explicit work stack so deep graphs can't
+/// blow the WASM call stack
+fn tarjan_scc_iterative(n: usize, adj: &[SmallBuffer<usize, 8>]) -> Vec<Vec<usize>> {
+    let mut index = 0usize;
+    let mut indices: Vec<Option<usize>> = vec![None; n];
+    let mut lowlink: Vec<usize> = vec![0; n];
+    let mut on_stack: Vec<bool> = vec![false; n];
+    let mut stack: Vec<usize> = Vec::with_capacity(n);
+    let mut sccs: Vec<Vec<usize>> = Vec::new();
+
+    // (node, index of next successor to visit)
+    let mut call_stack: Vec<(usize, usize)> = Vec::new();
+
+    for start in 0..n {
+        if indices[start].is_some() {
+            continue;
+        }
+
+        indices[start] = Some(index);
+        lowlink[start] = index;
+        index += 1;
+        stack.push(start);
+        on_stack[start] = true;
+        call_stack.push((start, 0));
+
+        while let Some(&(v, pos)) = call_stack.last() {
+            if pos < adj[v].len() {
+                let w = adj[v][pos];
+                call_stack.last_mut().unwrap().1 += 1;
+
+                if indices[w].is_none() {
+                    indices[w] = Some(index);
+                    lowlink[w] = index;
+                    index += 1;
+                    stack.push(w);
+                    on_stack[w] = true;
+                    call_stack.push((w, 0));
+                } else if on_stack[w] {
+                    lowlink[v] = lowlink[v].min(indices[w].unwrap());
+                }
+            } else {
+                call_stack.pop();
+
+                if let Some(&(parent, _)) = call_stack.last() {
+                    lowlink[parent] = lowlink[parent].min(lowlink[v]);
+                }
+
+                if lowlink[v] == indices[v].unwrap() {
+                    let mut scc = Vec::new();
+                    while let Some(w) = stack.pop() {
+                        on_stack[w] = false;
+                        scc.push(w);
+                        if w == v {
+                            break;
+                        }
+                    }
+                    sccs.push(scc);
+                }
+            }
+        }
+    }
+
+    sccs
+}
+
+/// Reconstruct one concrete closed walk within a strongly-connected component
+fn reconstruct_cycle(scc: &[usize], adj: &[SmallBuffer<usize, 8>]) -> Option<Vec<usize>> {
+    if scc.len() == 1 {
+        let v = scc[0];
+        return if adj[v].contains(&v) { Some(vec![v, v]) } else { None };
+    }
+
+    let members: FxHashSet<usize> = scc.iter().copied().collect();
+    let start = scc[0];
+    let mut visited: FxHashSet<usize> = FxHashSet::default();
+    let mut path: Vec<usize> = vec![start];
+
+    if cycle_dfs(start, start, &members, adj, &mut visited, &mut path) {
+        Some(path)
+    } else {
+        None
+    }
+}
+
+/// DFS within a component looking for an edge back to `start`
+fn cycle_dfs(
+    node: usize,
+    start: usize,
+    members: &FxHashSet<usize>,
+    adj: &[SmallBuffer<usize, 8>],
+    visited: &mut FxHashSet<usize>,
+    path: &mut Vec<usize>,
+) -> bool {
+    visited.insert(node);
+
+    for &w in &adj[node] {
+        if !members.contains(&w) {
+            continue;
+        }
+        if w == start && path.len() > 1 {
+            path.push(w);
+            return true;
+        }
+        if !visited.contains(&w) {
+            path.push(w);
+            if cycle_dfs(w, start, members, adj, visited, path) {
+                return true;
+            }
+            path.pop();
+        }
+    }
+
+    false
+}
+
+/// Compute the immediate dominator of every bead
+///
+/// Beads typically have several sources (empty `blocked_by`), so a virtual
+/// root is synthesized with an edge to every source before running the
+/// Cooper-Harvey-Kennedy iterative dominator algorithm.
+#[inline]
+pub fn compute_dominators_impl(beads_json: &str) -> Result<String, JsValue> {
+    let beads: Vec<BeadNode> = serde_json::from_str(beads_json)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+    let result = compute_dominators_internal(&beads);
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Serialize error: {}", e)))
+}
+
+/// Internal CHK dominator computation; returns bead id -> immediate dominator id
+fn compute_dominators_internal(beads: &[BeadNode]) -> FxHashMap<String, String> {
+    let n = beads.len();
+    if n == 0 {
+        return FxHashMap::default();
+    }
+
+    let mut id_to_index: FxHashMap<&str, usize> = FxHashMap::default();
+    id_to_index.reserve(n);
+    for (i, bead) in beads.iter().enumerate() {
+        id_to_index.insert(&bead.id, i);
+    }
+
+    let mut successors: Vec<SmallBuffer<usize, 8>> = vec![SmallBuffer::new(); n];
+    for (i, bead) in beads.iter().enumerate() {
+        for blocked in &bead.blocks {
+            if let Some(&j) = id_to_index.get(blocked.as_str()) {
+                successors[i].push(j);
+            }
+        }
+    }
+
+    // Virtual root lives at index `n`
+    let root = n;
+    let sources: Vec<usize> = (0..n).filter(|&i| beads[i].blocked_by.is_empty()).collect();
+
+    // Reverse-postorder numbering via DFS from the virtual root
+    let mut rpo_number: Vec<usize> = vec![usize::MAX; n + 1];
+    let mut postorder: Vec<usize> = Vec::with_capacity(n + 1);
+    let mut visited: Vec<bool> = vec![false; n + 1];
+    let mut stack: Vec<(usize, usize)> = vec![(root, 0)];
+    visited[root] = true;
+
+    while let Some(&(v, pos)) = stack.last() {
+        let neighbors: &[usize] = if v == root { &sources } else { &successors[v] };
+        if pos < neighbors.len() {
+            let w = neighbors[pos];
+            stack.last_mut().unwrap().1 += 1;
+            if !visited[w] {
+                visited[w] = true;
+                stack.push((w, 0));
+            }
+        } else {
+            stack.pop();
+            postorder.push(v);
+        }
+    }
+    for (number, &node) in postorder.iter().rev().enumerate() {
+        rpo_number[node] = number;
+    }
+
+    // Predecessors over the virtual graph (root -> each source)
+    let mut preds: Vec<SmallBuffer<usize, 8>> = vec![SmallBuffer::new(); n + 1];
+    for (u, succ) in successors.iter().enumerate() {
+        for &v in succ {
+            preds[v].push(u);
+        }
+    }
+    for &s in &sources {
+        preds[s].push(root);
+    }
+
+    let mut idom: Vec<Option<usize>> = vec![None; n + 1];
+    idom[root] = Some(root);
+
+    // Process only nodes reachable from the root, in reverse-postorder
+    let mut rpo_order: Vec<usize> = (0..=n).filter(|&v| rpo_number[v] != usize::MAX).collect();
+    rpo_order.sort_by_key(|&v| rpo_number[v]);
+
+    let intersect = |a: usize, b: usize, idom: &[Option<usize>], rpo_number: &[usize]| -> usize {
+        let mut finger1 = a;
+        let mut finger2 = b;
+        while finger1 != finger2 {
+            while rpo_number[finger1] > rpo_number[finger2] {
+                finger1 = idom[finger1].unwrap();
+            }
+            while rpo_number[finger2] > rpo_number[finger1] {
+                finger2 = idom[finger2].unwrap();
+            }
+        }
+        finger1
+    };
+
+    let mut changed = true;
+    while changed {
+        changed = false;
+        for &v in &rpo_order {
+            if v == root {
+                continue;
+            }
+
+            let mut new_idom: Option<usize> = None;
+            for &p in &preds[v] {
+                if idom[p].is_none() {
+                    continue;
+                }
+                new_idom = Some(match new_idom {
+                    None => p,
+                    Some(existing) => intersect(p, existing, &idom, &rpo_number),
+                });
+            }
+
+            if new_idom.is_some() && new_idom != idom[v] {
+                idom[v] = new_idom;
+                changed = true;
+            }
+        }
+    }
+
+    let mut result: FxHashMap<String, String> = FxHashMap::default();
+    result.reserve(n);
+    for i in 0..n {
+        let dominator = match idom[i] {
+            Some(d) if d != root => beads[d].id.clone(),
+            _ => beads[i].id.clone(),
+        };
+        result.insert(beads[i].id.clone(), dominator);
+    }
+
+    result
+}
+
+/// A fixed-width bit-packed set over the node-index space
+#[derive(Clone)]
+struct Bitset {
+    words: Vec<u64>,
+}
+
+impl Bitset {
+    fn new(n: usize) -> Self {
+        Self { words: vec![0u64; n.div_ceil(64).max(1)] }
+    }
+
+    #[inline(always)]
+    fn set(&mut self, i: usize) {
+        self.words[i / 64] |= 1u64 << (i % 64);
+    }
+
+    #[inline(always)]
+    fn get(&self, i: usize) -> bool {
+        (self.words[i / 64] >> (i % 64)) & 1 == 1
+    }
+
+    fn union_with(&mut self, other: &Bitset) {
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a |= b;
+        }
+    }
+
+    fn iter_set(&self, n: usize) -> impl Iterator<Item = usize> + '_ {
+        (0..n).filter(move |&i| self.get(i))
+    }
+}
+
+/// Topological order of bead indices via Kahn's algorithm over `blocks`/`blocked_by`
+fn topo_order_indices(beads: &[BeadNode], id_to_index: &FxHashMap<&str, usize>) -> Result<Vec<usize>, JsValue> {
+    let n = beads.len();
+    let mut in_degree: Vec<usize> = vec![0; n];
+    let mut successors: Vec<SmallBuffer<usize, 8>> = vec![SmallBuffer::new(); n];
+
+    for (i, bead) in beads.iter().enumerate() {
+        in_degree[i] = bead.blocked_by.len();
+        for blocked in &bead.blocks {
+            if let Some(&j) = id_to_index.get(blocked.as_str()) {
+                successors[i].push(j);
+            }
+        }
+    }
+
+    let mut queue: std::collections::VecDeque<usize> = std::collections::VecDeque::with_capacity(n);
+    for i in 0..n {
+        if in_degree[i] == 0 {
+            queue.push_back(i);
+        }
+    }
+
+    let mut order: Vec<usize> = Vec::with_capacity(n);
+    while let Some(i) = queue.pop_front() {
+        order.push(i);
+        for &j in &successors[i] {
+            in_degree[j] -= 1;
+            if in_degree[j] == 0 {
+                queue.push_back(j);
+            }
+        }
+    }
+
+    if order.len() != n {
+        return Err(JsValue::from_str("Cycle detected in dependency graph"));
+    }
+
+    Ok(order)
+}
+
+/// Per-node bitset of every transitive ancestor reachable through `blocked_by`
+///
+/// Processes nodes in topological order (blockers before the beads they
+/// block) so `reach[v]` is simply the union of `{b} U reach[b]` over v's
+/// direct blockers `b`.
+fn compute_ancestor_bitsets(beads: &[BeadNode], id_to_index: &FxHashMap<&str, usize>) -> Result<Vec<Bitset>, JsValue> {
+    let n = beads.len();
+    let topo_order = topo_order_indices(beads, id_to_index)?;
+
+    let mut reach: Vec<Bitset> = vec![Bitset::new(n); n];
+    for &v in &topo_order {
+        for dep in &beads[v].blocked_by {
+            if let Some(&b) = id_to_index.get(dep.as_str()) {
+                reach[v].set(b);
+                let blocker_reach = reach[b].clone();
+                reach[v].union_with(&blocker_reach);
+            }
+        }
+    }
+
+    Ok(reach)
+}
+
+/// For every bead, the full set of transitive ancestors reachable through
+/// `blocked_by` chains ("everything that must finish before X")
+#[inline]
+pub fn transitive_blockers_impl(beads_json: &str) -> Result<String, JsValue> {
+    let beads: Vec<BeadNode> = serde_json::from_str(beads_json)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+    let n = beads.len();
+    let mut id_to_index: FxHashMap<&str, usize> = FxHashMap::default();
+    id_to_index.reserve(n);
+    for (i, bead) in beads.iter().enumerate() {
+        id_to_index.insert(&bead.id, i);
+    }
+
+    let reach = compute_ancestor_bitsets(&beads, &id_to_index)?;
+
+    let mut result: FxHashMap<String, Vec<String>> = FxHashMap::default();
+    result.reserve(n);
+    for i in 0..n {
+        result.insert(beads[i].id.clone(), reach[i].iter_set(n).map(|j| beads[j].id.clone()).collect());
+    }
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Serialize error: {}", e)))
+}
+
+/// Transitive reduction: drop any direct `blocks` edge `u -> v` that is still
+/// implied by another path from `u` to `v`, yielding the minimal equivalent
+/// dependency set
+#[inline]
+pub fn reduce_edges_impl(beads_json: &str) -> Result<String, JsValue> {
+    let beads: Vec<BeadNode> = serde_json::from_str(beads_json)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+    let n = beads.len();
+    let mut id_to_index: FxHashMap<&str, usize> = FxHashMap::default();
+    id_to_index.reserve(n);
+    for (i, bead) in beads.iter().enumerate() {
+        id_to_index.insert(&bead.id, i);
+    }
+
+    let reach = compute_ancestor_bitsets(&beads, &id_to_index)?;
+
+    let mut direct_successors: Vec<SmallBuffer<usize, 8>> = vec![SmallBuffer::new(); n];
+    for (i, bead) in beads.iter().enumerate() {
+        for blocked in &bead.blocks {
+            if let Some(&j) = id_to_index.get(blocked.as_str()) {
+                direct_successors[i].push(j);
+            }
+        }
+    }
+
+    let mut result: FxHashMap<String, Vec<String>> = FxHashMap::default();
+    result.reserve(n);
+    for (u, succs) in direct_successors.iter().enumerate() {
+        let mut kept: Vec<String> = Vec::new();
+        for &v in succs {
+            let redundant = succs.iter().any(|&w| w != v && reach[v].get(w));
+            if !redundant {
+                kept.push(beads[v].id.clone());
+            }
+        }
+        result.insert(beads[u].id.clone(), kept);
+    }
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Serialize error: {}", e)))
+}
+
+/// Run the Critical Path Method over `duration` and report full per-bead timing
+///
+/// Earliest start `ES[v]` is the max over blockers `p` of `ES[p] + dur[p]`
+/// (missing durations treated as zero); a backward pass seeded from the
+/// project finish time then yields latest start `LS`, and `slack = LS - ES`.
+/// Beads with zero slack make up the critical path.
+#[inline]
+pub fn critical_path_schedule_impl(beads_json: &str) -> Result<String, JsValue> {
+    let beads: Vec<BeadNode> = serde_json::from_str(beads_json)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+    let result = critical_path_schedule_internal(&beads)?;
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Serialize error: {}", e)))
+}
+
+/// Internal CPM forward/backward pass computing full per-bead schedule timing
+fn critical_path_schedule_internal(beads: &[BeadNode]) -> Result<ScheduleAnalysisResult, JsValue> {
+    let n = beads.len();
+    if n == 0 {
+        return Ok(ScheduleAnalysisResult {
+            total_duration: 0,
+            critical_path: vec![],
+            schedule: FxHashMap::default(),
+        });
+    }
+
+    let mut id_to_index: FxHashMap<&str, usize> = FxHashMap::default();
+    id_to_index.reserve(n);
+    for (i, bead) in beads.iter().enumerate() {
+        id_to_index.insert(&bead.id, i);
+    }
+
+    let durations: Vec<u32> = beads.iter().map(|b| b.duration.unwrap_or(0)).collect();
+    let topo_order = topo_order_indices(beads, &id_to_index)?;
+
+    // Forward pass: earliest start/finish
+    let mut earliest_start: Vec<u32> = vec![0; n];
+    let mut earliest_finish: Vec<u32> = vec![0; n];
+    for &v in &topo_order {
+        let es = beads[v].blocked_by.iter()
+            .filter_map(|dep| id_to_index.get(dep.as_str()))
+            .map(|&p| earliest_finish[p])
+            .max()
+            .unwrap_or(0);
+        earliest_start[v] = es;
+        earliest_finish[v] = es + durations[v];
+    }
+
+    let total_duration = earliest_finish.iter().copied().max().unwrap_or(0);
+
+    // Backward pass: latest start/finish
+    let mut successors: Vec<SmallBuffer<usize, 8>> = vec![SmallBuffer::new(); n];
+    for (u, bead) in beads.iter().enumerate() {
+        for blocked in &bead.blocks {
+            if let Some(&v) = id_to_index.get(blocked.as_str()) {
+                successors[u].push(v);
+            }
+        }
+    }
+
+    let mut latest_start: Vec<u32> = vec![0; n];
+    for &v in topo_order.iter().rev() {
+        let lf = successors[v].iter()
+            .map(|&s| latest_start[s])
+            .min()
+            .unwrap_or(total_duration);
+        latest_start[v] = lf.saturating_sub(durations[v]);
+    }
+
+    let mut schedule: FxHashMap<String, BeadSchedule> = FxHashMap::default();
+    schedule.reserve(n);
+    let mut critical_indices: Vec<usize> = Vec::new();
+    for v in 0..n {
+        let slack = latest_start[v].saturating_sub(earliest_start[v]);
+        schedule.insert(beads[v].id.clone(), BeadSchedule {
+            earliest_start: earliest_start[v],
+            latest_start: latest_start[v],
+            slack,
+        });
+        if slack == 0 {
+            critical_indices.push(v);
+        }
+    }
+    critical_indices.sort_by_key(|&v| earliest_start[v]);
+
+    let critical_path = critical_indices.iter().map(|&v| beads[v].id.clone()).collect();
+
+    Ok(ScheduleAnalysisResult { total_duration, critical_path, schedule })
+}
+
+/// A bound on the number of elementary cycles an enumeration will return,
+/// to keep pathologically cyclic graphs from blowing up output size
+const DEFAULT_MAX_ELEMENTARY_CYCLES: usize = 1000;
+
+/// Enumerate every elementary cycle (ordered closed walk with no repeated
+/// interior vertex) using Johnson's algorithm, scoped to each nontrivial SCC
+#[inline]
+pub fn enumerate_elementary_cycles_impl(beads_json: &str, max_cycles: usize) -> Result<String, JsValue> {
+    let beads: Vec<BeadNode> = serde_json::from_str(beads_json)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+    let cap = if max_cycles == 0 { DEFAULT_MAX_ELEMENTARY_CYCLES } else { max_cycles };
+    let cycles = enumerate_elementary_cycles_internal(&beads, cap);
+
+    serde_json::to_string(&cycles)
+        .map_err(|e| JsValue::from_str(&format!("Serialize error: {}", e)))
+}
+
+/// Johnson's elementary-circuit search, run independently within each
+/// strongly-connected component found by the existing iterative Tarjan pass
+fn enumerate_elementary_cycles_internal(beads: &[BeadNode], max_cycles: usize) -> Vec<Vec<String>> {
+    let n = beads.len();
+    if n == 0 {
+        return vec![];
+    }
+
+    let mut id_to_index: FxHashMap<&str, usize> = FxHashMap::default();
+    id_to_index.reserve(n);
+    for (i, bead) in beads.iter().enumerate() {
+        id_to_index.insert(&bead.id, i);
+    }
+
+    let mut adj: Vec<SmallBuffer<usize, 8>> = vec![SmallBuffer::new(); n];
+    for bead in beads {
+        if let Some(&u) = id_to_index.get(bead.id.as_str()) {
+            for blocked in &bead.blocks {
+                if let Some(&v) = id_to_index.get(blocked.as_str()) {
+                    adj[u].push(v);
+                }
+            }
+        }
+    }
+
+    let mut cycles: Vec<Vec<usize>> = Vec::new();
+
+    for scc in tarjan_scc_iterative(n, &adj) {
+        if cycles.len() >= max_cycles {
+            break;
+        }
+        if scc.len() == 1 && !adj[scc[0]].contains(&scc[0]) {
+            continue;
+        }
+
+        let members: FxHashSet<usize> = scc.iter().copied().collect();
+        let scc_adj: FxHashMap<usize, Vec<usize>> = scc.iter()
+            .map(|&v| (v, adj[v].iter().copied().filter(|w| members.contains(w)).collect()))
+            .collect();
+
+        let mut sorted_members: Vec<usize> = scc.clone();
+        sorted_members.sort_unstable();
+
+        for &s in &sorted_members {
+            if cycles.len() >= max_cycles {
+                break;
+            }
+            // Restrict the search to vertices >= s, so each cycle is only
+            // ever discovered from its least vertex and never re-emitted
+            // from the other rotations of the same cycle.
+            let restricted: FxHashSet<usize> = members.iter().copied().filter(|&v| v >= s).collect();
+            let restricted_adj: FxHashMap<usize, Vec<usize>> = restricted.iter()
+                .map(|&v| {
+                    let neighbors = scc_adj.get(&v)
+                        .map(|ns| ns.iter().copied().filter(|w| restricted.contains(w)).collect())
+                        .unwrap_or_default();
+                    (v, neighbors)
+                })
+                .collect();
+
+            let mut blocked: FxHashSet<usize> = FxHashSet::default();
+            let mut block_map: FxHashMap<usize, FxHashSet<usize>> = FxHashMap::default();
+            let mut path: Vec<usize> = vec![s];
+            johnson_circuit(s, s, &restricted_adj, &mut blocked, &mut block_map, &mut path, &mut cycles, max_cycles);
+        }
+    }
+
+    cycles.into_iter()
+        .map(|cycle| cycle.into_iter().map(|i| beads[i].id.clone()).collect())
+        .collect()
+}
+
+/// Blocked-set circuit search (the DFS core of Johnson's algorithm) for one
+/// strongly-connected component, rooted at its least-indexed vertex `start`
+fn johnson_circuit(
+    v: usize,
+    start: usize,
+    adj: &FxHashMap<usize, Vec<usize>>,
+    blocked: &mut FxHashSet<usize>,
+    block_map: &mut FxHashMap<usize, FxHashSet<usize>>,
+    path: &mut Vec<usize>,
+    cycles: &mut Vec<Vec<usize>>,
+    max_cycles: usize,
+) -> bool {
+    if cycles.len() >= max_cycles {
+        return false;
+    }
+
+    let mut found = false;
+    blocked.insert(v);
+
+    if let Some(neighbors) = adj.get(&v) {
+        for &w in neighbors {
+            if cycles.len() >= max_cycles {
+                break;
+            }
+            if w == start {
+                let mut cycle = path.clone();
+                cycle.push(start);
+                cycles.push(cycle);
+                found = true;
+            } else if !blocked.contains(&w) {
+                path.push(w);
+                if johnson_circuit(w, start, adj, blocked, block_map, path, cycles, max_cycles) {
+                    found = true;
+                }
+                path.pop();
+            }
+        }
+    }
+
+    if found {
+        unblock(v, blocked, block_map);
+    } else if let Some(neighbors) = adj.get(&v) {
+        for &w in neighbors {
+            block_map.entry(w).or_default().insert(v);
+        }
+    }
+
+    found
+}
+
+/// Unblock `v` and transitively every node whose circuit search was waiting on it
+fn unblock(v: usize, blocked: &mut FxHashSet<usize>, block_map: &mut FxHashMap<usize, FxHashSet<usize>>) {
+    blocked.remove(&v);
+    if let Some(waiting) = block_map.remove(&v) {
+        for w in waiting {
+            if blocked.contains(&w) {
+                unblock(w, blocked, block_map);
+            }
+        }
+    }
+}
+
+/// Compute a stable 128-bit fingerprint of the dependency structure
+///
+/// Combines a per-node FxHash of the bead id plus its sorted neighbor ids
+/// (`blocked_by` and `blocks` together) into a 128-bit lane, then folds all
+/// lanes together with XOR so neither node nor edge ordering in the input
+/// JSON changes the result. `fold_status` optionally mixes each bead's
+/// `status` into its lane for callers (like ready-set caching) that do care
+/// when status changes; fields such as `title` never affect the fingerprint.
+#[inline]
+pub fn graph_fingerprint_impl(beads_json: &str, fold_status: bool) -> Result<String, JsValue> {
+    let beads: Vec<BeadNode> = serde_json::from_str(beads_json)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+    Ok(format!("{:032x}", graph_fingerprint_internal(&beads, fold_status)))
+}
+
+/// Internal order-independent 128-bit fingerprint computation
+pub(crate) fn graph_fingerprint_internal(beads: &[BeadNode], fold_status: bool) -> u128 {
+    let mut fingerprint: u128 = 0;
+
+    for bead in beads {
+        let mut neighbors: Vec<&str> = bead.blocked_by.iter()
+            .map(String::as_str)
+            .chain(bead.blocks.iter().map(String::as_str))
+            .collect();
+        neighbors.sort_unstable();
+
+        let mut payload = String::with_capacity(bead.id.len() + 32);
+        payload.push_str(&bead.id);
+        for neighbor in &neighbors {
+            payload.push('\0');
+            payload.push_str(neighbor);
+        }
+        if fold_status {
+            payload.push('\0');
+            payload.push_str(&bead.status);
+        }
+
+        let lane_low = fx_hash_str(&payload);
+        payload.push_str("#lane2");
+        let lane_high = fx_hash_str(&payload);
+
+        let node_fingerprint = ((lane_high as u128) << 64) | (lane_low as u128);
+        fingerprint ^= node_fingerprint;
+    }
+
+    fingerprint
+}
+
 /// Compute execution levels using BFS from sources
 ///
 /// Optimized with FxHash and pre-allocated vectors
@@ -366,6 +1134,233 @@ mod tests {
         assert!(has_cycle_impl(&beads_json).unwrap());
     }
 
+    #[test]
+    fn test_find_cycles_groups_two_independent_loops() {
+        // a <-> b, and separately c -> d -> c; e is acyclic
+        let beads = vec![
+            BeadNode { id: "a".to_string(), title: "A".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["b".to_string()], duration: None },
+            BeadNode { id: "b".to_string(), title: "B".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["a".to_string()], duration: None },
+            BeadNode { id: "c".to_string(), title: "C".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["d".to_string()], duration: None },
+            BeadNode { id: "d".to_string(), title: "D".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["c".to_string()], duration: None },
+            BeadNode { id: "e".to_string(), title: "E".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec![], duration: None },
+        ];
+
+        let result = find_cycles_internal(&beads);
+        assert_eq!(result.components.len(), 2);
+        assert_eq!(result.example_cycles.len(), 2);
+
+        for cycle in &result.example_cycles {
+            assert_eq!(cycle.first(), cycle.last());
+            assert!(cycle.len() >= 3);
+        }
+
+        let flat: FxHashSet<&String> = result.components.iter().flatten().collect();
+        assert!(!flat.contains(&"e".to_string()));
+    }
+
+    #[test]
+    fn test_find_cycles_detects_self_loop() {
+        let beads = vec![
+            BeadNode { id: "a".to_string(), title: "A".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec!["a".to_string()], blocks: vec!["a".to_string()], duration: None },
+        ];
+
+        let result = find_cycles_internal(&beads);
+        assert_eq!(result.components, vec![vec!["a".to_string()]]);
+        assert_eq!(result.example_cycles, vec![vec!["a".to_string(), "a".to_string()]]);
+    }
+
+    #[test]
+    fn test_dominators_diamond_converges_at_join() {
+        // a -> b, a -> c, b -> d, c -> d
+        let beads = vec![
+            BeadNode { id: "a".to_string(), title: "A".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["b".to_string(), "c".to_string()], duration: None },
+            BeadNode { id: "b".to_string(), title: "B".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec!["a".to_string()], blocks: vec!["d".to_string()], duration: None },
+            BeadNode { id: "c".to_string(), title: "C".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec!["a".to_string()], blocks: vec!["d".to_string()], duration: None },
+            BeadNode { id: "d".to_string(), title: "D".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec!["b".to_string(), "c".to_string()], blocks: vec![], duration: None },
+        ];
+
+        let doms = compute_dominators_internal(&beads);
+        assert_eq!(doms.get("a"), Some(&"a".to_string()));
+        assert_eq!(doms.get("b"), Some(&"a".to_string()));
+        assert_eq!(doms.get("c"), Some(&"a".to_string()));
+        // d is reachable via both b and c, so its idom is where those paths fork: a
+        assert_eq!(doms.get("d"), Some(&"a".to_string()));
+    }
+
+    #[test]
+    fn test_dominators_linear_chain() {
+        let beads = vec![
+            BeadNode { id: "a".to_string(), title: "A".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["b".to_string()], duration: None },
+            BeadNode { id: "b".to_string(), title: "B".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec!["a".to_string()], blocks: vec!["c".to_string()], duration: None },
+            BeadNode { id: "c".to_string(), title: "C".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec!["b".to_string()], blocks: vec![], duration: None },
+        ];
+
+        let doms = compute_dominators_internal(&beads);
+        assert_eq!(doms.get("a"), Some(&"a".to_string()));
+        assert_eq!(doms.get("b"), Some(&"a".to_string()));
+        assert_eq!(doms.get("c"), Some(&"b".to_string()));
+    }
+
+    #[test]
+    fn test_dominators_two_independent_sources() {
+        let beads = vec![
+            BeadNode { id: "a".to_string(), title: "A".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec![], duration: None },
+            BeadNode { id: "b".to_string(), title: "B".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec![], duration: None },
+        ];
+
+        let doms = compute_dominators_internal(&beads);
+        assert_eq!(doms.get("a"), Some(&"a".to_string()));
+        assert_eq!(doms.get("b"), Some(&"b".to_string()));
+    }
+
+    #[test]
+    fn test_transitive_blockers_diamond_includes_all_ancestors() {
+        // a -> b, a -> c, b -> d, c -> d
+        let beads = vec![
+            BeadNode { id: "a".to_string(), title: "A".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["b".to_string(), "c".to_string()], duration: None },
+            BeadNode { id: "b".to_string(), title: "B".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec!["a".to_string()], blocks: vec!["d".to_string()], duration: None },
+            BeadNode { id: "c".to_string(), title: "C".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec!["a".to_string()], blocks: vec!["d".to_string()], duration: None },
+            BeadNode { id: "d".to_string(), title: "D".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec!["b".to_string(), "c".to_string()], blocks: vec![], duration: None },
+        ];
+
+        let beads_json = serde_json::to_string(&beads).unwrap();
+        let result = transitive_blockers_impl(&beads_json).unwrap();
+        let ancestors: FxHashMap<String, Vec<String>> = serde_json::from_str(&result).unwrap();
+
+        assert!(ancestors.get("a").unwrap().is_empty());
+        let mut d_ancestors = ancestors.get("d").unwrap().clone();
+        d_ancestors.sort();
+        assert_eq!(d_ancestors, vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+    }
+
+    #[test]
+    fn test_reduce_edges_drops_redundant_shortcut() {
+        // a -> b -> c, plus a redundant direct edge a -> c
+        let beads = vec![
+            BeadNode { id: "a".to_string(), title: "A".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["b".to_string(), "c".to_string()], duration: None },
+            BeadNode { id: "b".to_string(), title: "B".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec!["a".to_string()], blocks: vec!["c".to_string()], duration: None },
+            BeadNode { id: "c".to_string(), title: "C".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec!["a".to_string(), "b".to_string()], blocks: vec![], duration: None },
+        ];
+
+        let beads_json = serde_json::to_string(&beads).unwrap();
+        let result = reduce_edges_impl(&beads_json).unwrap();
+        let reduced: FxHashMap<String, Vec<String>> = serde_json::from_str(&result).unwrap();
+
+        assert_eq!(reduced.get("a"), Some(&vec!["b".to_string()]));
+        assert_eq!(reduced.get("b"), Some(&vec!["c".to_string()]));
+        assert_eq!(reduced.get("c"), Some(&vec![]));
+    }
+
+    #[test]
+    fn test_transitive_blockers_empty_graph() {
+        let result = transitive_blockers_impl("[]").unwrap();
+        let ancestors: FxHashMap<String, Vec<String>> = serde_json::from_str(&result).unwrap();
+        assert!(ancestors.is_empty());
+    }
+
+    #[test]
+    fn test_critical_path_schedule_linear_chain_has_no_slack() {
+        let beads = vec![
+            BeadNode { id: "a".to_string(), title: "A".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["b".to_string()], duration: Some(5) },
+            BeadNode { id: "b".to_string(), title: "B".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec!["a".to_string()], blocks: vec![], duration: Some(3) },
+        ];
+
+        let result = critical_path_schedule_internal(&beads).unwrap();
+        assert_eq!(result.total_duration, 8);
+        assert_eq!(result.critical_path, vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(result.schedule.get("a").unwrap().slack, 0);
+        assert_eq!(result.schedule.get("b").unwrap().slack, 0);
+    }
+
+    #[test]
+    fn test_critical_path_schedule_reports_slack_on_short_branch() {
+        // a -> b (long) -> d, a -> c (short) -> d
+        let beads = vec![
+            BeadNode { id: "a".to_string(), title: "A".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["b".to_string(), "c".to_string()], duration: Some(0) },
+            BeadNode { id: "b".to_string(), title: "B".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec!["a".to_string()], blocks: vec!["d".to_string()], duration: Some(10) },
+            BeadNode { id: "c".to_string(), title: "C".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec!["a".to_string()], blocks: vec!["d".to_string()], duration: Some(2) },
+            BeadNode { id: "d".to_string(), title: "D".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec!["b".to_string(), "c".to_string()], blocks: vec![], duration: Some(1) },
+        ];
+
+        let result = critical_path_schedule_internal(&beads).unwrap();
+        assert_eq!(result.total_duration, 11);
+        assert_eq!(result.schedule.get("c").unwrap().slack, 8);
+        assert_eq!(result.schedule.get("b").unwrap().slack, 0);
+        assert!(result.critical_path.contains(&"b".to_string()));
+        assert!(!result.critical_path.contains(&"c".to_string()));
+    }
+
+    #[test]
+    fn test_enumerate_elementary_cycles_finds_both_loops() {
+        let beads = vec![
+            BeadNode { id: "a".to_string(), title: "A".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["b".to_string()], duration: None },
+            BeadNode { id: "b".to_string(), title: "B".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["a".to_string()], duration: None },
+            BeadNode { id: "c".to_string(), title: "C".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["d".to_string()], duration: None },
+            BeadNode { id: "d".to_string(), title: "D".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["c".to_string()], duration: None },
+        ];
+
+        let cycles = enumerate_elementary_cycles_internal(&beads, 100);
+        assert_eq!(cycles.len(), 2);
+        for cycle in &cycles {
+            assert_eq!(cycle.first(), cycle.last());
+            assert_eq!(cycle.len(), 3);
+        }
+    }
+
+    #[test]
+    fn test_enumerate_elementary_cycles_respects_cap() {
+        // A 4-cycle with every edge in both directions, so several elementary circuits exist
+        let beads = vec![
+            BeadNode { id: "a".to_string(), title: "A".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["b".to_string(), "d".to_string()], duration: None },
+            BeadNode { id: "b".to_string(), title: "B".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["c".to_string(), "a".to_string()], duration: None },
+            BeadNode { id: "c".to_string(), title: "C".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["d".to_string(), "b".to_string()], duration: None },
+            BeadNode { id: "d".to_string(), title: "D".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["a".to_string(), "c".to_string()], duration: None },
+        ];
+
+        let cycles = enumerate_elementary_cycles_internal(&beads, 1);
+        assert_eq!(cycles.len(), 1);
+    }
+
+    #[test]
+    fn test_graph_fingerprint_is_order_independent() {
+        let forward = vec![
+            BeadNode { id: "a".to_string(), title: "A".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["b".to_string()], duration: None },
+            BeadNode { id: "b".to_string(), title: "B".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec!["a".to_string()], blocks: vec![], duration: None },
+        ];
+        let mut reversed = forward.clone();
+        reversed.reverse();
+
+        assert_eq!(graph_fingerprint_internal(&forward, false), graph_fingerprint_internal(&reversed, false));
+    }
+
+    #[test]
+    fn test_graph_fingerprint_ignores_title_but_not_structure() {
+        let beads = vec![
+            BeadNode { id: "a".to_string(), title: "A".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["b".to_string()], duration: None },
+            BeadNode { id: "b".to_string(), title: "B".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec!["a".to_string()], blocks: vec![], duration: None },
+        ];
+        let mut retitled = beads.clone();
+        retitled[0].title = "Something Else".to_string();
+
+        let mut restructured = beads.clone();
+        restructured[1].blocked_by.clear();
+
+        assert_eq!(graph_fingerprint_internal(&beads, false), graph_fingerprint_internal(&retitled, false));
+        assert_ne!(graph_fingerprint_internal(&beads, false), graph_fingerprint_internal(&restructured, false));
+    }
+
+    #[test]
+    fn test_graph_fingerprint_fold_status_changes_result() {
+        let beads = vec![
+            BeadNode { id: "a".to_string(), title: "A".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec![], duration: None },
+        ];
+        let mut closed = beads.clone();
+        closed[0].status = "closed".to_string();
+
+        assert_eq!(graph_fingerprint_internal(&beads, false), graph_fingerprint_internal(&closed, false));
+        assert_ne!(graph_fingerprint_internal(&beads, true), graph_fingerprint_internal(&closed, true));
+    }
+
     #[test]
     fn test_ready_beads() {
         let beads = vec![
@@ -0,0 +1,439 @@
+//! Persistent Graph Handle
+//!
+//! The free-function exports in this crate re-parse the full `beads_json` and
+//! rebuild all lookup maps and successor buffers from scratch on every
+//! call, which dominates cost once a UI is issuing several queries per small
+//! edit. `BeadGraphHandle` instead owns the parsed graph across calls and
+//! exposes incremental mutators that only touch the downstream region
+//! affected by a change.
+//!
+//! `set_duration` (a pure value edit, no topology change) takes the fast
+//! path: it recomputes the forward (earliest start/finish) pass only for the
+//! region reachable from the changed bead via `blocks`, then redoes the
+//! backward pass over the existing topological order, which is already
+//! O(V+E) with no allocation or re-parsing. `add_edge`/`remove_edge` change
+//! topology, so they fall back to a full recompute of the topological order
+//! and both passes -- still far cheaper than round-tripping through JSON.
+
+use wasm_bindgen::prelude::*;
+use gastown_shared::{FxHashMap, FxHashSet, pool::SmallBuffer};
+use crate::{BeadNode, CriticalPathResult, LevelsResult};
+
+/// A small fixed-capacity LRU cache keyed by graph version, so repeated
+/// queries against an unchanged (or recently-seen) version are near-free
+/// without letting cached results grow unbounded. `entries` is ordered
+/// least- to most-recently-used; a hit moves its entry to the back, and an
+/// eviction drops from the front.
+struct SizedCache {
+    capacity: usize,
+    entries: Vec<(u64, String)>,
+}
+
+impl SizedCache {
+    fn new(capacity: usize) -> Self {
+        Self { capacity, entries: Vec::with_capacity(capacity) }
+    }
+
+    fn get(&mut self, key: u64) -> Option<&str> {
+        let pos = self.entries.iter().position(|(k, _)| *k == key)?;
+        let entry = self.entries.remove(pos);
+        self.entries.push(entry);
+        self.entries.last().map(|(_, v)| v.as_str())
+    }
+
+    fn put(&mut self, key: u64, value: String) {
+        if let Some(pos) = self.entries.iter().position(|(k, _)| *k == key) {
+            self.entries.remove(pos);
+        } else if self.entries.len() >= self.capacity {
+            self.entries.remove(0);
+        }
+        self.entries.push((key, value));
+    }
+}
+
+/// Stateful handle owning a parsed bead graph plus cached CPM arrays
+#[wasm_bindgen]
+pub struct BeadGraphHandle {
+    beads: Vec<BeadNode>,
+    id_to_index: FxHashMap<String, usize>,
+    successors: Vec<SmallBuffer<usize, 8>>,
+    topo_order: Vec<usize>,
+    earliest_start: Vec<u32>,
+    earliest_finish: Vec<u32>,
+    latest_start: Vec<u32>,
+    project_duration: u32,
+    version: u64,
+    critical_path_cache: SizedCache,
+    levels_cache: SizedCache,
+}
+
+const RESULT_CACHE_CAPACITY: usize = 8;
+
+#[wasm_bindgen]
+impl BeadGraphHandle {
+    /// Parse `beads_json` once and build the persistent graph state
+    #[wasm_bindgen(constructor)]
+    pub fn new(beads_json: &str) -> Result<BeadGraphHandle, JsValue> {
+        let beads: Vec<BeadNode> = serde_json::from_str(beads_json)
+            .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+        let mut handle = BeadGraphHandle {
+            beads,
+            id_to_index: FxHashMap::default(),
+            successors: Vec::new(),
+            topo_order: Vec::new(),
+            earliest_start: Vec::new(),
+            earliest_finish: Vec::new(),
+            latest_start: Vec::new(),
+            project_duration: 0,
+            version: 0,
+            critical_path_cache: SizedCache::new(RESULT_CACHE_CAPACITY),
+            levels_cache: SizedCache::new(RESULT_CACHE_CAPACITY),
+        };
+
+        handle.rebuild_topology()?;
+        handle.recompute_forward_full();
+        handle.recompute_backward();
+
+        Ok(handle)
+    }
+
+    /// Update a bead's duration in place; recomputes only the downstream
+    /// region reachable from it
+    #[wasm_bindgen(js_name = setDuration)]
+    pub fn set_duration(&mut self, id: &str, duration: u32) -> Result<(), JsValue> {
+        let i = *self.id_to_index.get(id)
+            .ok_or_else(|| JsValue::from_str(&format!("Unknown bead id: {}", id)))?;
+
+        self.beads[i].duration = Some(duration);
+
+        let affected = self.downstream_closure(i);
+        self.recompute_forward_region(&affected);
+        self.recompute_backward();
+        self.bump_version();
+
+        Ok(())
+    }
+
+    /// Add a `from -> to` (from blocks to) dependency edge
+    #[wasm_bindgen(js_name = addEdge)]
+    pub fn add_edge(&mut self, from: &str, to: &str) -> Result<(), JsValue> {
+        let from_i = *self.id_to_index.get(from)
+            .ok_or_else(|| JsValue::from_str(&format!("Unknown bead id: {}", from)))?;
+        let to_i = *self.id_to_index.get(to)
+            .ok_or_else(|| JsValue::from_str(&format!("Unknown bead id: {}", to)))?;
+
+        if self.successors[from_i].contains(&to_i) {
+            return Ok(());
+        }
+
+        self.beads[from_i].blocks.push(to.to_string());
+        self.beads[to_i].blocked_by.push(from.to_string());
+        self.successors[from_i].push(to_i);
+
+        if let Err(e) = self.rebuild_topology() {
+            // Roll back: the new edge introduced a cycle
+            self.beads[from_i].blocks.pop();
+            self.beads[to_i].blocked_by.pop();
+            self.successors[from_i].retain(|&j| j != to_i);
+            let _ = self.rebuild_topology();
+            self.recompute_forward_full();
+            self.recompute_backward();
+            return Err(e);
+        }
+
+        self.recompute_forward_full();
+        self.recompute_backward();
+        self.bump_version();
+
+        Ok(())
+    }
+
+    /// Remove a `from -> to` dependency edge, if present
+    #[wasm_bindgen(js_name = removeEdge)]
+    pub fn remove_edge(&mut self, from: &str, to: &str) -> Result<(), JsValue> {
+        let from_i = *self.id_to_index.get(from)
+            .ok_or_else(|| JsValue::from_str(&format!("Unknown bead id: {}", from)))?;
+        let to_i = *self.id_to_index.get(to)
+            .ok_or_else(|| JsValue::from_str(&format!("Unknown bead id: {}", to)))?;
+
+        self.beads[from_i].blocks.retain(|b| b != to);
+        self.beads[to_i].blocked_by.retain(|b| b != from);
+        self.successors[from_i].retain(|&j| j != to_i);
+
+        self.rebuild_topology()?;
+        self.recompute_forward_full();
+        self.recompute_backward();
+        self.bump_version();
+
+        Ok(())
+    }
+
+    /// Current critical path, served from cache when the graph hasn't
+    /// changed since the last call with this version
+    #[wasm_bindgen(js_name = criticalPath)]
+    pub fn critical_path(&mut self) -> Result<String, JsValue> {
+        if let Some(cached) = self.critical_path_cache.get(self.version) {
+            return Ok(cached.to_string());
+        }
+
+        let mut slack: FxHashMap<String, u32> = FxHashMap::default();
+        slack.reserve(self.beads.len());
+        let mut critical_indices: Vec<usize> = Vec::new();
+
+        for i in 0..self.beads.len() {
+            let s = self.latest_start[i].saturating_sub(self.earliest_start[i]);
+            slack.insert(self.beads[i].id.clone(), s);
+            if s == 0 {
+                critical_indices.push(i);
+            }
+        }
+
+        let path = self.build_critical_path(&critical_indices);
+
+        let result = CriticalPathResult {
+            path,
+            total_duration: self.project_duration,
+            slack,
+        };
+
+        let json = serde_json::to_string(&result)
+            .map_err(|e| JsValue::from_str(&format!("Serialize error: {}", e)))?;
+
+        self.critical_path_cache.put(self.version, json.clone());
+        Ok(json)
+    }
+
+    /// Current execution levels, served from cache when unchanged
+    #[wasm_bindgen]
+    pub fn levels(&mut self) -> Result<String, JsValue> {
+        if let Some(cached) = self.levels_cache.get(self.version) {
+            return Ok(cached.to_string());
+        }
+
+        let n = self.beads.len();
+        let mut level_of: Vec<usize> = vec![0; n];
+        for &i in &self.topo_order {
+            let bead = &self.beads[i];
+            let level = bead.blocked_by.iter()
+                .filter_map(|dep| self.id_to_index.get(dep.as_str()))
+                .map(|&j| level_of[j] + 1)
+                .max()
+                .unwrap_or(0);
+            level_of[i] = level;
+        }
+
+        let max_level = level_of.iter().copied().max().unwrap_or(0);
+        let mut levels_vec: Vec<Vec<String>> = vec![Vec::new(); max_level + 1];
+        for i in 0..n {
+            levels_vec[level_of[i]].push(self.beads[i].id.clone());
+        }
+
+        let result = LevelsResult {
+            max_parallelism: levels_vec.iter().map(|l| l.len()).max().unwrap_or(0),
+            levels: levels_vec,
+        };
+
+        let json = serde_json::to_string(&result)
+            .map_err(|e| JsValue::from_str(&format!("Serialize error: {}", e)))?;
+
+        self.levels_cache.put(self.version, json.clone());
+        Ok(json)
+    }
+}
+
+impl BeadGraphHandle {
+    fn bump_version(&mut self) {
+        self.version += 1;
+    }
+
+    /// Rebuild `id_to_index`, `successors` and `topo_order` from `self.beads`
+    fn rebuild_topology(&mut self) -> Result<(), JsValue> {
+        let n = self.beads.len();
+
+        self.id_to_index = FxHashMap::default();
+        self.id_to_index.reserve(n);
+        for (i, bead) in self.beads.iter().enumerate() {
+            self.id_to_index.insert(bead.id.clone(), i);
+        }
+
+        self.successors = vec![SmallBuffer::new(); n];
+        let mut in_degree: Vec<usize> = vec![0; n];
+        for (i, bead) in self.beads.iter().enumerate() {
+            in_degree[i] = bead.blocked_by.len();
+            for blocked in &bead.blocks {
+                if let Some(&j) = self.id_to_index.get(blocked.as_str()) {
+                    self.successors[i].push(j);
+                }
+            }
+        }
+
+        let mut queue: std::collections::VecDeque<usize> = std::collections::VecDeque::with_capacity(n);
+        for i in 0..n {
+            if in_degree[i] == 0 {
+                queue.push_back(i);
+            }
+        }
+
+        let mut order: Vec<usize> = Vec::with_capacity(n);
+        while let Some(i) = queue.pop_front() {
+            order.push(i);
+            for &j in &self.successors[i] {
+                in_degree[j] -= 1;
+                if in_degree[j] == 0 {
+                    queue.push_back(j);
+                }
+            }
+        }
+
+        if order.len() != n {
+            return Err(JsValue::from_str("Cycle detected in dependency graph"));
+        }
+
+        self.topo_order = order;
+        self.earliest_start = vec![0; n];
+        self.earliest_finish = vec![0; n];
+        self.latest_start = vec![0; n];
+
+        Ok(())
+    }
+
+    /// All nodes reachable from `start` via `blocks` (inclusive of `start`)
+    fn downstream_closure(&self, start: usize) -> FxHashSet<usize> {
+        let mut seen: FxHashSet<usize> = FxHashSet::default();
+        let mut stack = vec![start];
+        seen.insert(start);
+        while let Some(i) = stack.pop() {
+            for &j in &self.successors[i] {
+                if seen.insert(j) {
+                    stack.push(j);
+                }
+            }
+        }
+        seen
+    }
+
+    fn recompute_forward_full(&mut self) {
+        let all: FxHashSet<usize> = (0..self.beads.len()).collect();
+        self.recompute_forward_region(&all);
+    }
+
+    /// Recompute earliest start/finish for `affected` nodes in topological
+    /// order; nodes outside `affected` keep their cached values, which are
+    /// still valid because none of their predecessors changed
+    fn recompute_forward_region(&mut self, affected: &FxHashSet<usize>) {
+        for &i in &self.topo_order {
+            if !affected.contains(&i) {
+                continue;
+            }
+            let bead = &self.beads[i];
+            let es = bead.blocked_by.iter()
+                .filter_map(|dep| self.id_to_index.get(dep.as_str()))
+                .map(|&j| self.earliest_finish[j])
+                .max()
+                .unwrap_or(0);
+            self.earliest_start[i] = es;
+            self.earliest_finish[i] = es + bead.duration.unwrap_or(1);
+        }
+
+        self.project_duration = self.earliest_finish.iter().copied().max().unwrap_or(0);
+    }
+
+    /// Backward pass is always recomputed in full: it's O(V+E) with no
+    /// allocation or re-parsing, and any earliest-finish change can shift
+    /// `project_duration`, which every sink's latest time depends on.
+    fn recompute_backward(&mut self) {
+        let project_duration = self.project_duration;
+        for &i in self.topo_order.iter().rev() {
+            let lf = self.successors[i].iter()
+                .map(|&j| self.latest_start[j])
+                .min()
+                .unwrap_or(project_duration);
+            self.latest_start[i] = lf.saturating_sub(self.beads[i].duration.unwrap_or(1));
+        }
+    }
+
+    fn build_critical_path(&self, critical_indices: &[usize]) -> Vec<String> {
+        if critical_indices.is_empty() {
+            return vec![];
+        }
+
+        let critical_set: FxHashSet<usize> = critical_indices.iter().copied().collect();
+
+        let start = critical_indices.iter().copied().find(|&i| {
+            !self.beads[i].blocked_by.iter()
+                .filter_map(|dep| self.id_to_index.get(dep.as_str()))
+                .any(|&j| critical_set.contains(&j))
+        });
+
+        let Some(start_idx) = start else {
+            return critical_indices.iter().map(|&i| self.beads[i].id.clone()).collect();
+        };
+
+        let mut path = vec![self.beads[start_idx].id.clone()];
+        let mut current = start_idx;
+        loop {
+            let next = self.successors[current].iter().copied().find(|&j| critical_set.contains(&j));
+            match next {
+                Some(j) => {
+                    path.push(self.beads[j].id.clone());
+                    current = j;
+                }
+                None => break,
+            }
+        }
+
+        path
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn linear_chain_json() -> String {
+        let beads = vec![
+            BeadNode { id: "a".to_string(), title: "A".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec![], blocks: vec!["b".to_string()], duration: Some(10) },
+            BeadNode { id: "b".to_string(), title: "B".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec!["a".to_string()], blocks: vec!["c".to_string()], duration: Some(20) },
+            BeadNode { id: "c".to_string(), title: "C".to_string(), status: "open".to_string(), priority: 0, blocked_by: vec!["b".to_string()], blocks: vec![], duration: Some(15) },
+        ];
+        serde_json::to_string(&beads).unwrap()
+    }
+
+    #[test]
+    fn test_handle_initial_critical_path() {
+        let mut handle = BeadGraphHandle::new(&linear_chain_json()).unwrap();
+        let result: CriticalPathResult = serde_json::from_str(&handle.critical_path().unwrap()).unwrap();
+        assert_eq!(result.total_duration, 45);
+        assert_eq!(result.path, vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+    }
+
+    #[test]
+    fn test_handle_set_duration_updates_makespan() {
+        let mut handle = BeadGraphHandle::new(&linear_chain_json()).unwrap();
+        handle.set_duration("b", 100).unwrap();
+
+        let result: CriticalPathResult = serde_json::from_str(&handle.critical_path().unwrap()).unwrap();
+        assert_eq!(result.total_duration, 125);
+    }
+
+    #[test]
+    fn test_handle_add_edge_rejects_cycle() {
+        let mut handle = BeadGraphHandle::new(&linear_chain_json()).unwrap();
+        assert!(handle.add_edge("c", "a").is_err());
+
+        // Handle should remain usable after a rejected mutation
+        let result: CriticalPathResult = serde_json::from_str(&handle.critical_path().unwrap()).unwrap();
+        assert_eq!(result.total_duration, 45);
+    }
+
+    #[test]
+    fn test_handle_remove_edge_splits_chain() {
+        let mut handle = BeadGraphHandle::new(&linear_chain_json()).unwrap();
+        handle.remove_edge("a", "b").unwrap();
+
+        let result: CriticalPathResult = serde_json::from_str(&handle.critical_path().unwrap()).unwrap();
+        // b -> c (35) is now the longest remaining chain; a (10) is independent
+        assert_eq!(result.total_duration, 35);
+    }
+}
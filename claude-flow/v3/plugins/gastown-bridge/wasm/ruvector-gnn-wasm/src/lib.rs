@@ -35,10 +35,18 @@ use gastown_shared::FxHashMap;
 mod dag;
 mod topo;
 mod critical;
+mod path;
+mod embeddings;
+mod handle;
+mod session;
 
 pub use dag::*;
 pub use topo::*;
 pub use critical::*;
+pub use path::*;
+pub use embeddings::*;
+pub use handle::BeadGraphHandle;
+pub use session::GraphSession;
 
 // ============================================================================
 // Core Types
@@ -64,10 +72,16 @@ pub struct BeadNode {
 pub struct GraphEdge {
     pub from: String,
     pub to: String,
-    #[serde(default)]
+    #[serde(default = "default_edge_weight")]
     pub weight: f64,
 }
 
+/// Default weight for an edge whose JSON omits `weight`
+#[inline(always)]
+fn default_edge_weight() -> f64 {
+    1.0
+}
+
 /// Dependency graph
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BeadGraph {
@@ -82,6 +96,11 @@ pub struct TopoSortResult {
     pub has_cycle: bool,
     #[serde(default)]
     pub cycle_nodes: Vec<String>,
+    /// Each genuine cycle (SCC of size > 1, or a self-loop) as its own ordered group,
+    /// alongside `cycle_nodes`, which holds a single cycle node (graphs of up to
+    /// 100 beads) or a flat bag of every unprocessed node (larger graphs)
+    #[serde(default)]
+    pub cycle_groups: Vec<Vec<String>>,
 }
 
 /// Critical path result
@@ -99,6 +118,96 @@ pub struct LevelsResult {
     pub max_parallelism: usize,
 }
 
+/// Resource-constrained schedule result (list scheduling over N workers)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ResourceScheduleResult {
+    pub starts: FxHashMap<String, u32>,
+    pub finishes: FxHashMap<String, u32>,
+    pub makespan: u32,
+}
+
+/// A single near-critical path within a slack tolerance
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NearCriticalPath {
+    pub path: Vec<String>,
+    pub duration: u32,
+    pub min_slack: u32,
+}
+
+/// Structured cycle report: every non-trivial SCC plus one concrete cycle per group
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CyclesResult {
+    pub components: Vec<Vec<String>>,
+    pub example_cycles: Vec<Vec<String>>,
+}
+
+/// Minimum-weight path between two beads over weighted dependency edges
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ShortestPathResult {
+    pub path: Vec<String>,
+    pub cost: f64,
+}
+
+/// Per-bead CPM timing: earliest/latest start and the resulting slack
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BeadSchedule {
+    pub earliest_start: u32,
+    pub latest_start: u32,
+    pub slack: u32,
+}
+
+/// Full CPM schedule analysis: project duration, the critical chain, and per-bead timing
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScheduleAnalysisResult {
+    pub total_duration: u32,
+    pub critical_path: Vec<String>,
+    pub schedule: FxHashMap<String, BeadSchedule>,
+}
+
+/// Full four-point CPM timing for a single bead: earliest/latest start and finish, plus slack
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CpmTiming {
+    pub id: String,
+    pub es: u32,
+    pub ef: u32,
+    pub ls: u32,
+    pub lf: u32,
+    pub slack: u32,
+}
+
+/// CPM analysis keyed by topological order: project makespan, per-bead timing, and the critical chain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CpmScheduleResult {
+    pub makespan: u32,
+    pub per_bead: Vec<CpmTiming>,
+    pub critical_path: Vec<String>,
+}
+
+/// A single bead's slot in a fixed-worker-count list schedule
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WorkerAssignment {
+    pub id: String,
+    pub worker: u32,
+    pub start: u32,
+    pub finish: u32,
+}
+
+/// Time-indexed, worker-bounded schedule produced by upward-rank list scheduling
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ListScheduleResult {
+    pub makespan: u32,
+    pub assignments: Vec<WorkerAssignment>,
+}
+
+/// A memoized topo sort plus wave grouping, tagged with the structural
+/// fingerprint it was computed from
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CachedSortResult {
+    pub fingerprint: String,
+    pub sort: TopoSortResult,
+    pub waves: Vec<Vec<String>>,
+}
+
 // ============================================================================
 // WASM Exports
 // ============================================================================
@@ -171,6 +280,189 @@ pub fn critical_path(beads_json: &str) -> Result<String, JsValue> {
     critical::critical_path_impl(beads_json)
 }
 
+/// Compute a realistic schedule bounded by a fixed number of concurrent workers
+///
+/// # Arguments
+/// * `beads_json` - Array of beads with durations as JSON string
+/// * `num_workers` - Maximum number of beads that may run concurrently
+///
+/// # Returns
+/// * `String` - ResourceScheduleResult as JSON string
+#[wasm_bindgen]
+#[inline]
+pub fn schedule_resource_constrained(beads_json: &str, num_workers: u32) -> Result<String, JsValue> {
+    critical::schedule_resource_constrained_impl(beads_json, num_workers)
+}
+
+/// Enumerate every near-critical path within a slack tolerance
+///
+/// # Arguments
+/// * `beads_json` - Array of beads with durations as JSON string
+/// * `slack_threshold` - Maximum per-node slack for a path to be reported
+/// * `max_paths` - Cap on the number of paths returned (0 = use a sane default)
+///
+/// # Returns
+/// * `String` - `Vec<NearCriticalPath>` as JSON string, sorted by duration descending
+#[wasm_bindgen]
+#[inline]
+pub fn critical_paths(beads_json: &str, slack_threshold: u32, max_paths: usize) -> Result<String, JsValue> {
+    critical::critical_paths_impl(beads_json, slack_threshold, max_paths)
+}
+
+/// Report distinct cycles as grouped strongly-connected components
+///
+/// # Arguments
+/// * `beads_json` - Array of beads as JSON string
+///
+/// # Returns
+/// * `String` - CyclesResult as JSON string
+#[wasm_bindgen]
+#[inline]
+pub fn find_cycles(beads_json: &str) -> Result<String, JsValue> {
+    dag::find_cycles_impl(beads_json)
+}
+
+/// Compute the immediate dominator of every bead
+///
+/// # Arguments
+/// * `beads_json` - Array of beads as JSON string
+///
+/// # Returns
+/// * `String` - `map<id, id>` of bead id to immediate-dominator bead id as JSON string
+#[wasm_bindgen]
+#[inline]
+pub fn compute_dominators(beads_json: &str) -> Result<String, JsValue> {
+    dag::compute_dominators_impl(beads_json)
+}
+
+/// Run the Critical Path Method over bead `duration`s and report full per-bead timing
+///
+/// # Arguments
+/// * `beads_json` - Array of beads with durations as JSON string
+///
+/// # Returns
+/// * `String` - `ScheduleAnalysisResult` as JSON string
+#[wasm_bindgen]
+#[inline]
+pub fn critical_path_schedule(beads_json: &str) -> Result<String, JsValue> {
+    dag::critical_path_schedule_impl(beads_json)
+}
+
+/// Compute the transitive reduction of the bead dependency graph in the toposort module,
+/// returning a pruned bead set that callers can persist as the simplified graph
+///
+/// # Arguments
+/// * `beads_json` - Array of beads as JSON string
+///
+/// # Returns
+/// * `String` - `Vec<BeadNode>` with redundant `blocks`/`blocked_by` edges removed, as JSON string
+#[wasm_bindgen]
+#[inline]
+pub fn transitive_reduction(beads_json: &str) -> Result<String, JsValue> {
+    topo::transitive_reduction_impl(beads_json)
+}
+
+/// Run the Critical Path Method over the toposort module's own topological order, reporting
+/// full four-point timing (ES/EF/LS/LF) and slack for every bead
+///
+/// # Arguments
+/// * `beads_json` - Array of beads with durations as JSON string
+///
+/// # Returns
+/// * `String` - `CpmScheduleResult` as JSON string
+#[wasm_bindgen]
+#[inline]
+pub fn cpm_schedule(beads_json: &str) -> Result<String, JsValue> {
+    topo::cpm_schedule_impl(beads_json)
+}
+
+/// Sort and wave-group beads, reusing the cached result when the graph's
+/// structural fingerprint hasn't changed
+///
+/// # Arguments
+/// * `beads_json` - Array of beads as JSON string
+/// * `previous_fingerprint` - Fingerprint hex string from a prior call; when
+///   the host already knows nothing changed since then, pass it to skip even
+///   the rehash. Pass `None` to always recompute the fingerprint and compare.
+///
+/// # Returns
+/// * `String` - `CachedSortResult` as JSON string
+#[wasm_bindgen]
+#[inline]
+pub fn topo_sort_cached(beads_json: &str, previous_fingerprint: Option<String>) -> Result<String, JsValue> {
+    topo::topo_sort_cached_impl(beads_json, previous_fingerprint)
+}
+
+/// Schedule beads onto a fixed number of workers via list scheduling weighted by
+/// upward rank (duration plus the critical length of work still ahead), breaking
+/// ties by descending `priority`
+///
+/// # Arguments
+/// * `beads_json` - Array of beads with durations as JSON string
+/// * `max_workers` - Maximum number of beads that may run concurrently
+///
+/// # Returns
+/// * `String` - `ListScheduleResult` as JSON string
+#[wasm_bindgen]
+#[inline]
+pub fn list_schedule(beads_json: &str, max_workers: u32) -> Result<String, JsValue> {
+    topo::list_schedule_impl(beads_json, max_workers)
+}
+
+/// Enumerate elementary cycles (concrete "A -> B -> C -> A" chains) using Johnson's algorithm
+///
+/// # Arguments
+/// * `beads_json` - Array of beads as JSON string
+/// * `max_cycles` - Cap on the number of cycles reported (0 = use a sane default)
+///
+/// # Returns
+/// * `String` - `Vec<Vec<id>>`, each an ordered closed walk, as JSON string
+#[wasm_bindgen]
+#[inline]
+pub fn enumerate_cycles(beads_json: &str, max_cycles: usize) -> Result<String, JsValue> {
+    dag::enumerate_elementary_cycles_impl(beads_json, max_cycles)
+}
+
+/// Compute a stable 128-bit fingerprint of the dependency structure, order-independent
+///
+/// # Arguments
+/// * `beads_json` - Array of beads as JSON string
+/// * `fold_status` - When true, also mix each bead's `status` into the fingerprint
+///
+/// # Returns
+/// * `String` - The fingerprint as a 32-character lowercase hex string
+#[wasm_bindgen]
+#[inline]
+pub fn graph_fingerprint(beads_json: &str, fold_status: bool) -> Result<String, JsValue> {
+    dag::graph_fingerprint_impl(beads_json, fold_status)
+}
+
+/// Compute the full set of transitive ancestors (through `blocked_by` chains) for every bead
+///
+/// # Arguments
+/// * `beads_json` - Array of beads as JSON string
+///
+/// # Returns
+/// * `String` - `map<id, Vec<id>>` of bead id to its transitive blocker ids as JSON string
+#[wasm_bindgen]
+#[inline]
+pub fn transitive_blockers(beads_json: &str) -> Result<String, JsValue> {
+    dag::transitive_blockers_impl(beads_json)
+}
+
+/// Compute the transitive reduction of the `blocks` dependency edges
+///
+/// # Arguments
+/// * `beads_json` - Array of beads as JSON string
+///
+/// # Returns
+/// * `String` - `map<id, Vec<id>>` of bead id to its reduced direct-successor ids as JSON string
+#[wasm_bindgen]
+#[inline]
+pub fn reduce_edges(beads_json: &str) -> Result<String, JsValue> {
+    dag::reduce_edges_impl(beads_json)
+}
+
 /// Build adjacency list from beads
 ///
 /// # Arguments
@@ -210,6 +502,45 @@ pub fn compute_levels(beads_json: &str) -> Result<String, JsValue> {
     dag::compute_levels_impl(beads_json)
 }
 
+/// Find the minimum-weight path between two beads over weighted dependency edges
+///
+/// # Arguments
+/// * `beads_json` - Array of beads as JSON string (adds isolated nodes to the graph)
+/// * `edges_json` - Array of `GraphEdge` as JSON string (absent `weight` defaults to 1.0)
+/// * `from_id` - Starting bead id
+/// * `to_id` - Target bead id
+/// * `heuristics_json` - Optional JSON map of bead id to an admissible lower-bound
+///   cost estimate to the goal; omit (or pass `None`) for plain Dijkstra
+///
+/// # Returns
+/// * `String` - `ShortestPathResult` as JSON string, or `null` if unreachable
+#[wasm_bindgen]
+#[inline]
+pub fn shortest_path(
+    beads_json: &str,
+    edges_json: &str,
+    from_id: &str,
+    to_id: &str,
+    heuristics_json: Option<String>,
+) -> Result<String, JsValue> {
+    path::shortest_path_impl(beads_json, edges_json, from_id, to_id, heuristics_json.as_deref())
+}
+
+/// Compute fixed-width structural embeddings for every bead via message passing
+///
+/// # Arguments
+/// * `beads_json` - Array of beads as JSON string
+/// * `dims` - Embedding width
+/// * `rounds` - Number of neighborhood-aggregation rounds
+///
+/// # Returns
+/// * `String` - `map<id, Vec<f32>>` as JSON string
+#[wasm_bindgen]
+#[inline]
+pub fn compute_embeddings(beads_json: &str, dims: usize, rounds: usize) -> Result<String, JsValue> {
+    embeddings::compute_embeddings_impl(beads_json, dims, rounds)
+}
+
 /// Get performance metrics
 ///
 /// Returns timing information for benchmarking
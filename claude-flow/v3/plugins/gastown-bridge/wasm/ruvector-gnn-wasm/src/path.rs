@@ -0,0 +1,217 @@
+//! Weighted Shortest-Path Query
+//!
+//! Point-to-point Dijkstra over the weighted dependency edges carried by
+//! `GraphEdge.weight`, with an optional A*-style admissible heuristic hook.
+//! A zero (or absent) heuristic degrades exactly to plain Dijkstra.
+
+use std::cmp::Ordering;
+use std::collections::BinaryHeap;
+use wasm_bindgen::prelude::*;
+use gastown_shared::FxHashMap;
+use crate::{BeadNode, GraphEdge, ShortestPathResult};
+
+/// Compute the minimum-weight path between `from_id` and `to_id`
+#[inline]
+pub fn shortest_path_impl(
+    beads_json: &str,
+    edges_json: &str,
+    from_id: &str,
+    to_id: &str,
+    heuristics_json: Option<&str>,
+) -> Result<String, JsValue> {
+    let beads: Vec<BeadNode> = serde_json::from_str(beads_json)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+    let edges: Vec<GraphEdge> = serde_json::from_str(edges_json)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+    let heuristics: FxHashMap<String, f64> = match heuristics_json {
+        Some(s) if !s.is_empty() => serde_json::from_str(s)
+            .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?,
+        _ => FxHashMap::default(),
+    };
+
+    let result = shortest_path_internal(&beads, &edges, from_id, to_id, &heuristics);
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Serialize error: {}", e)))
+}
+
+/// A min-heap entry ordered by ascending priority (`cost + heuristic`)
+struct HeapEntry {
+    priority: f64,
+    node: usize,
+}
+
+impl PartialEq for HeapEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.priority == other.priority
+    }
+}
+impl Eq for HeapEntry {}
+impl PartialOrd for HeapEntry {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+impl Ord for HeapEntry {
+    fn cmp(&self, other: &Self) -> Ordering {
+        // Reversed so BinaryHeap (a max-heap) pops the smallest priority first
+        other.priority.partial_cmp(&self.priority).unwrap_or(Ordering::Equal)
+    }
+}
+
+/// Internal Dijkstra/A* search over the weighted edge list
+fn shortest_path_internal(
+    beads: &[BeadNode],
+    edges: &[GraphEdge],
+    from_id: &str,
+    to_id: &str,
+    heuristics: &FxHashMap<String, f64>,
+) -> Option<ShortestPathResult> {
+    let mut id_to_index: FxHashMap<&str, usize> = FxHashMap::default();
+    let mut ids: Vec<&str> = Vec::new();
+
+    for bead in beads {
+        id_to_index.entry(bead.id.as_str()).or_insert_with(|| {
+            ids.push(bead.id.as_str());
+            ids.len() - 1
+        });
+    }
+    for edge in edges {
+        for id in [edge.from.as_str(), edge.to.as_str()] {
+            id_to_index.entry(id).or_insert_with(|| {
+                ids.push(id);
+                ids.len() - 1
+            });
+        }
+    }
+
+    let n = ids.len();
+    let mut adj: Vec<Vec<(usize, f64)>> = vec![Vec::new(); n];
+    for edge in edges {
+        if let (Some(&u), Some(&v)) = (id_to_index.get(edge.from.as_str()), id_to_index.get(edge.to.as_str())) {
+            adj[u].push((v, edge.weight));
+        }
+    }
+
+    let start = *id_to_index.get(from_id)?;
+    let goal = *id_to_index.get(to_id)?;
+
+    let heuristic = |node: usize| -> f64 { *heuristics.get(ids[node]).unwrap_or(&0.0) };
+
+    let mut dist: Vec<f64> = vec![f64::INFINITY; n];
+    let mut prev: Vec<Option<usize>> = vec![None; n];
+    let mut visited: Vec<bool> = vec![false; n];
+
+    dist[start] = 0.0;
+    let mut heap: BinaryHeap<HeapEntry> = BinaryHeap::new();
+    heap.push(HeapEntry { priority: heuristic(start), node: start });
+
+    while let Some(HeapEntry { node, .. }) = heap.pop() {
+        if visited[node] {
+            continue;
+        }
+        visited[node] = true;
+
+        if node == goal {
+            break;
+        }
+
+        for &(next, weight) in &adj[node] {
+            if visited[next] {
+                continue;
+            }
+            let candidate = dist[node] + weight;
+            if candidate < dist[next] {
+                dist[next] = candidate;
+                prev[next] = Some(node);
+                heap.push(HeapEntry { priority: candidate + heuristic(next), node: next });
+            }
+        }
+    }
+
+    if dist[goal].is_infinite() {
+        return None;
+    }
+
+    let mut path_idx = vec![goal];
+    let mut current = goal;
+    while let Some(p) = prev[current] {
+        path_idx.push(p);
+        current = p;
+    }
+    path_idx.reverse();
+
+    Some(ShortestPathResult {
+        path: path_idx.into_iter().map(|i| ids[i].to_string()).collect(),
+        cost: dist[goal],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bead(id: &str) -> BeadNode {
+        BeadNode {
+            id: id.to_string(),
+            title: id.to_string(),
+            status: "open".to_string(),
+            priority: 0,
+            blocked_by: vec![],
+            blocks: vec![],
+            duration: None,
+        }
+    }
+
+    #[test]
+    fn test_shortest_path_picks_cheaper_route() {
+        let beads = vec![bead("a"), bead("b"), bead("c"), bead("d")];
+        let edges = vec![
+            GraphEdge { from: "a".to_string(), to: "d".to_string(), weight: 10.0 },
+            GraphEdge { from: "a".to_string(), to: "b".to_string(), weight: 1.0 },
+            GraphEdge { from: "b".to_string(), to: "c".to_string(), weight: 1.0 },
+            GraphEdge { from: "c".to_string(), to: "d".to_string(), weight: 1.0 },
+        ];
+
+        let result = shortest_path_internal(&beads, &edges, "a", "d", &FxHashMap::default()).unwrap();
+        assert_eq!(result.path, vec!["a".to_string(), "b".to_string(), "c".to_string(), "d".to_string()]);
+        assert_eq!(result.cost, 3.0);
+    }
+
+    #[test]
+    fn test_shortest_path_unreachable_returns_none() {
+        let beads = vec![bead("a"), bead("b")];
+        let edges: Vec<GraphEdge> = vec![];
+
+        assert!(shortest_path_internal(&beads, &edges, "a", "b", &FxHashMap::default()).is_none());
+    }
+
+    #[test]
+    fn test_shortest_path_defaults_missing_weight_to_one() {
+        let beads = vec![bead("a"), bead("b")];
+        let json = r#"[{"from":"a","to":"b"}]"#;
+        let edges: Vec<GraphEdge> = serde_json::from_str(json).unwrap();
+
+        let result = shortest_path_internal(&beads, &edges, "a", "b", &FxHashMap::default()).unwrap();
+        assert_eq!(result.cost, 1.0);
+    }
+
+    #[test]
+    fn test_shortest_path_with_heuristic_matches_plain_dijkstra() {
+        let beads = vec![bead("a"), bead("b"), bead("c")];
+        let edges = vec![
+            GraphEdge { from: "a".to_string(), to: "b".to_string(), weight: 2.0 },
+            GraphEdge { from: "b".to_string(), to: "c".to_string(), weight: 2.0 },
+        ];
+
+        let mut heuristics = FxHashMap::default();
+        heuristics.insert("a".to_string(), 4.0);
+        heuristics.insert("b".to_string(), 2.0);
+        heuristics.insert("c".to_string(), 0.0);
+
+        let result = shortest_path_internal(&beads, &edges, "a", "c", &heuristics).unwrap();
+        assert_eq!(result.cost, 4.0);
+        assert_eq!(result.path, vec!["a".to_string(), "b".to_string(), "c".to_string()]);
+    }
+}
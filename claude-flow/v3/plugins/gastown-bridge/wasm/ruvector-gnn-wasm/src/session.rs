@@ -0,0 +1,485 @@
+//! Persistent Interned Graph Session
+//!
+//! The free-function entry points in this crate re-parse `beads_json` and
+//! rebuild `String`-keyed maps on every call. `GraphSession` instead parses once,
+//! interns every bead id through the shared `StringInterner` into a compact
+//! `Symbol`, and stores adjacency as `Vec<SmallBuffer<u32, 8>>` over symbol
+//! indices. Queries then run directly on integers -- no string hashing, no
+//! re-parsing -- and `patch` applies incremental add/remove edits in place,
+//! bumping a version counter so cached query results are only recomputed
+//! after a `patch`, whether or not it changed anything (the same obligation-forest-style
+//! incremental model `BeadGraphHandle` uses for CPM).
+//!
+//! Removed nodes are tombstoned (`alive[sym] = false`) rather than
+//! compacted, since symbols are also referenced by other nodes' adjacency
+//! lists and the interner never reclaims indices.
+
+use wasm_bindgen::prelude::*;
+use gastown_shared::{FxHashMap, StringInterner, Symbol, pool::SmallBuffer};
+use crate::BeadNode;
+
+/// A small fixed-capacity FIFO cache keyed by session version
+struct SizedCache {
+    capacity: usize,
+    entries: Vec<(u64, String)>,
+}
+
+impl SizedCache {
+    fn new(capacity: usize) -> Self {
+        Self { capacity, entries: Vec::with_capacity(capacity) }
+    }
+
+    fn get(&self, key: u64) -> Option<&str> {
+        self.entries.iter().find(|(k, _)| *k == key).map(|(_, v)| v.as_str())
+    }
+
+    fn put(&mut self, key: u64, value: String) {
+        if self.entries.iter().any(|(k, _)| *k == key) {
+            return;
+        }
+        if self.entries.len() >= self.capacity {
+            self.entries.remove(0);
+        }
+        self.entries.push((key, value));
+    }
+}
+
+const RESULT_CACHE_CAPACITY: usize = 8;
+
+/// Stateful session owning an interned bead graph across repeated queries
+#[wasm_bindgen]
+pub struct GraphSession {
+    interner: StringInterner,
+    id_to_symbol: FxHashMap<String, u32>,
+    alive: Vec<bool>,
+    title: Vec<String>,
+    status: Vec<String>,
+    priority: Vec<u32>,
+    duration: Vec<Option<u32>>,
+    blocked_by: Vec<SmallBuffer<u32, 8>>,
+    blocks: Vec<SmallBuffer<u32, 8>>,
+    version: u64,
+    ready_cache: SizedCache,
+    levels_cache: SizedCache,
+}
+
+#[wasm_bindgen]
+impl GraphSession {
+    /// Parse `beads_json` once and intern every bead id into the session
+    #[wasm_bindgen(constructor)]
+    pub fn new(beads_json: &str) -> Result<GraphSession, JsValue> {
+        let beads: Vec<BeadNode> = serde_json::from_str(beads_json)
+            .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+        let mut session = GraphSession {
+            interner: StringInterner::with_capacity(beads.len()),
+            id_to_symbol: FxHashMap::default(),
+            alive: Vec::new(),
+            title: Vec::new(),
+            status: Vec::new(),
+            priority: Vec::new(),
+            duration: Vec::new(),
+            blocked_by: Vec::new(),
+            blocks: Vec::new(),
+            version: 0,
+            ready_cache: SizedCache::new(RESULT_CACHE_CAPACITY),
+            levels_cache: SizedCache::new(RESULT_CACHE_CAPACITY),
+        };
+
+        for bead in &beads {
+            session.upsert_bead(bead);
+        }
+
+        Ok(session)
+    }
+
+    /// Apply an incremental edit: upsert every bead in `added_json`, then
+    /// remove every id in `removed_ids_json`, invalidating cached results
+    #[wasm_bindgen]
+    pub fn patch(&mut self, added_json: &str, removed_ids_json: &str) -> Result<(), JsValue> {
+        let added: Vec<BeadNode> = serde_json::from_str(added_json)
+            .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+        let removed_ids: Vec<String> = serde_json::from_str(removed_ids_json)
+            .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+        for bead in &added {
+            self.upsert_bead(bead);
+        }
+        for id in &removed_ids {
+            if let Some(&sym) = self.id_to_symbol.get(id) {
+                self.remove_node(sym as usize);
+            }
+        }
+
+        self.version += 1;
+        Ok(())
+    }
+
+    /// Number of live beads currently tracked by the session
+    #[wasm_bindgen(js_name = nodeCount)]
+    pub fn node_count(&self) -> usize {
+        self.alive.iter().filter(|&&a| a).count()
+    }
+
+    /// True if the live dependency graph has a cycle
+    #[wasm_bindgen(js_name = hasCycle)]
+    pub fn has_cycle(&self) -> bool {
+        let n = self.alive.len();
+        let mut in_degree: Vec<usize> = vec![0; n];
+        for i in 0..n {
+            if !self.alive[i] {
+                continue;
+            }
+            in_degree[i] = self.blocked_by[i].iter().filter(|&&b| self.alive[b as usize]).count();
+        }
+
+        let mut queue: Vec<usize> = (0..n).filter(|&i| self.alive[i] && in_degree[i] == 0).collect();
+        let mut visited = 0usize;
+        let live_count = self.alive.iter().filter(|&&a| a).count();
+
+        while let Some(i) = queue.pop() {
+            visited += 1;
+            for &s in &self.blocks[i] {
+                let s = s as usize;
+                if !self.alive[s] {
+                    continue;
+                }
+                // `blocks`/`blocked_by` aren't required to be reciprocal, so
+                // `s` may not have counted this edge into its own in-degree;
+                // once its count is spent it is already queued, so skip it
+                // rather than underflow or queue it a second time.
+                if in_degree[s] == 0 {
+                    continue;
+                }
+                in_degree[s] -= 1;
+                if in_degree[s] == 0 {
+                    queue.push(s);
+                }
+            }
+        }
+
+        visited != live_count
+    }
+
+    /// Live beads whose blockers are all resolved (closed or no longer present)
+    #[wasm_bindgen(js_name = readyBeads)]
+    pub fn ready_beads(&mut self) -> String {
+        if let Some(cached) = self.ready_cache.get(self.version) {
+            return cached.to_string();
+        }
+
+        let ready: Vec<String> = (0..self.alive.len())
+            .filter(|&i| self.alive[i] && self.status[i] != "closed")
+            .filter(|&i| {
+                self.blocked_by[i].iter().all(|&b| {
+                    let b = b as usize;
+                    !self.alive[b] || self.status[b] == "closed"
+                })
+            })
+            .map(|i| self.title_id(i))
+            .collect();
+
+        let json = serde_json::to_string(&ready).unwrap_or_else(|_| "[]".to_string());
+        self.ready_cache.put(self.version, json.clone());
+        json
+    }
+
+    /// Execution levels (beads at the same level can run in parallel) as a `LevelsResult` JSON string
+    #[wasm_bindgen]
+    pub fn levels(&mut self) -> Result<String, JsValue> {
+        if let Some(cached) = self.levels_cache.get(self.version) {
+            return Ok(cached.to_string());
+        }
+
+        let n = self.alive.len();
+        let mut in_degree: Vec<usize> = vec![0; n];
+        for i in 0..n {
+            if self.alive[i] {
+                in_degree[i] = self.blocked_by[i].iter().filter(|&&b| self.alive[b as usize]).count();
+            }
+        }
+
+        let mut level_of: Vec<i64> = vec![-1; n];
+        let mut queue: Vec<usize> = (0..n).filter(|&i| self.alive[i] && in_degree[i] == 0).collect();
+        for &i in &queue {
+            level_of[i] = 0;
+        }
+
+        let mut head = 0;
+        while head < queue.len() {
+            let i = queue[head];
+            head += 1;
+            for &s in &self.blocks[i] {
+                let s = s as usize;
+                if !self.alive[s] {
+                    continue;
+                }
+                in_degree[s] -= 1;
+                level_of[s] = level_of[s].max(level_of[i] + 1);
+                if in_degree[s] == 0 {
+                    queue.push(s);
+                }
+            }
+        }
+
+        if queue.len() != self.node_count() {
+            return Err(JsValue::from_str("Cycle detected in dependency graph"));
+        }
+
+        let max_level = level_of.iter().copied().max().unwrap_or(0).max(0) as usize;
+        let mut levels_vec: Vec<Vec<String>> = vec![Vec::new(); max_level + 1];
+        for i in 0..n {
+            if self.alive[i] {
+                levels_vec[level_of[i] as usize].push(self.title_id(i));
+            }
+        }
+
+        let result = crate::LevelsResult {
+            max_parallelism: levels_vec.iter().map(|l| l.len()).max().unwrap_or(0),
+            levels: levels_vec,
+        };
+
+        let json = serde_json::to_string(&result)
+            .map_err(|e| JsValue::from_str(&format!("Serialize error: {}", e)))?;
+        self.levels_cache.put(self.version, json.clone());
+        Ok(json)
+    }
+
+    /// Immediate dominator of every live bead, as `map<id, id>` JSON
+    #[wasm_bindgen]
+    pub fn dominators(&self) -> Result<String, JsValue> {
+        let n = self.alive.len();
+        let root = n;
+        let sources: Vec<usize> = (0..n)
+            .filter(|&i| self.alive[i] && self.blocked_by[i].iter().all(|&b| !self.alive[b as usize]))
+            .collect();
+
+        let mut rpo_number: Vec<usize> = vec![usize::MAX; n + 1];
+        let mut postorder: Vec<usize> = Vec::with_capacity(n + 1);
+        let mut visited: Vec<bool> = vec![false; n + 1];
+        let mut stack: Vec<(usize, usize)> = vec![(root, 0)];
+        visited[root] = true;
+
+        while let Some(&(v, pos)) = stack.last() {
+            let neighbors: Vec<usize> = if v == root {
+                sources.clone()
+            } else {
+                self.blocks[v].iter().copied().map(|j| j as usize).filter(|&j| self.alive[j]).collect()
+            };
+            if pos < neighbors.len() {
+                let w = neighbors[pos];
+                stack.last_mut().unwrap().1 += 1;
+                if !visited[w] {
+                    visited[w] = true;
+                    stack.push((w, 0));
+                }
+            } else {
+                stack.pop();
+                postorder.push(v);
+            }
+        }
+        for (number, &node) in postorder.iter().rev().enumerate() {
+            rpo_number[node] = number;
+        }
+
+        let mut preds: Vec<SmallBuffer<usize, 8>> = vec![SmallBuffer::new(); n + 1];
+        for i in 0..n {
+            if !self.alive[i] {
+                continue;
+            }
+            for &s in &self.blocks[i] {
+                let s = s as usize;
+                if self.alive[s] {
+                    preds[s].push(i);
+                }
+            }
+        }
+        for &s in &sources {
+            preds[s].push(root);
+        }
+
+        let mut idom: Vec<Option<usize>> = vec![None; n + 1];
+        idom[root] = Some(root);
+
+        let mut rpo_order: Vec<usize> = (0..=n).filter(|&v| rpo_number[v] != usize::MAX).collect();
+        rpo_order.sort_by_key(|&v| rpo_number[v]);
+
+        let intersect = |a: usize, b: usize, idom: &[Option<usize>], rpo_number: &[usize]| -> usize {
+            let mut finger1 = a;
+            let mut finger2 = b;
+            while finger1 != finger2 {
+                while rpo_number[finger1] > rpo_number[finger2] {
+                    finger1 = idom[finger1].unwrap();
+                }
+                while rpo_number[finger2] > rpo_number[finger1] {
+                    finger2 = idom[finger2].unwrap();
+                }
+            }
+            finger1
+        };
+
+        let mut changed = true;
+        while changed {
+            changed = false;
+            for &v in &rpo_order {
+                if v == root {
+                    continue;
+                }
+                let mut new_idom: Option<usize> = None;
+                for &p in &preds[v] {
+                    if idom[p].is_none() {
+                        continue;
+                    }
+                    new_idom = Some(match new_idom {
+                        None => p,
+                        Some(existing) => intersect(p, existing, &idom, &rpo_number),
+                    });
+                }
+                if new_idom.is_some() && new_idom != idom[v] {
+                    idom[v] = new_idom;
+                    changed = true;
+                }
+            }
+        }
+
+        let mut result: FxHashMap<String, String> = FxHashMap::default();
+        for i in 0..n {
+            if !self.alive[i] {
+                continue;
+            }
+            let dominator = match idom[i] {
+                Some(d) if d != root => self.title_id(d),
+                _ => self.title_id(i),
+            };
+            result.insert(self.title_id(i), dominator);
+        }
+
+        serde_json::to_string(&result)
+            .map_err(|e| JsValue::from_str(&format!("Serialize error: {}", e)))
+    }
+}
+
+impl GraphSession {
+    /// Resolve a symbol index back to its original bead id
+    fn title_id(&self, index: usize) -> String {
+        self.interner
+            .resolve(Symbol::from_index(index as u32))
+            .unwrap_or("")
+            .to_string()
+    }
+
+    /// Insert or overwrite a bead's attributes and adjacency by symbol index
+    fn upsert_bead(&mut self, bead: &BeadNode) {
+        let sym = self.interner.intern(&bead.id);
+        let index = sym.index() as usize;
+        self.ensure_capacity(index + 1);
+        self.id_to_symbol.insert(bead.id.clone(), sym.index());
+
+        self.alive[index] = true;
+        self.title[index] = bead.title.clone();
+        self.status[index] = bead.status.clone();
+        self.priority[index] = bead.priority;
+        self.duration[index] = bead.duration;
+
+        // Neighbor ids may be interned here for the first time (dangling
+        // edges to beads that never appear as their own node), so their
+        // symbol indices can exceed `index` and must size the arrays too.
+        let blocked_by: SmallBuffer<u32, 8> = bead.blocked_by.iter()
+            .map(|id| self.interner.intern(id).index())
+            .collect();
+        let blocks: SmallBuffer<u32, 8> = bead.blocks.iter()
+            .map(|id| self.interner.intern(id).index())
+            .collect();
+        for &sym_index in blocked_by.iter().chain(blocks.iter()) {
+            self.ensure_capacity(sym_index as usize + 1);
+        }
+
+        self.blocked_by[index] = blocked_by;
+        self.blocks[index] = blocks;
+    }
+
+    /// Tombstone a node; its symbol stays reserved but drops out of every query
+    fn remove_node(&mut self, index: usize) {
+        if index >= self.alive.len() {
+            return;
+        }
+        self.alive[index] = false;
+        self.blocked_by[index].clear();
+        self.blocks[index].clear();
+    }
+
+    fn ensure_capacity(&mut self, len: usize) {
+        if self.alive.len() >= len {
+            return;
+        }
+        self.alive.resize(len, false);
+        self.title.resize(len, String::new());
+        self.status.resize(len, String::new());
+        self.priority.resize(len, 0);
+        self.duration.resize(len, None);
+        self.blocked_by.resize(len, SmallBuffer::new());
+        self.blocks.resize(len, SmallBuffer::new());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn bead(id: &str, blocked_by: Vec<&str>, blocks: Vec<&str>) -> BeadNode {
+        BeadNode {
+            id: id.to_string(),
+            title: id.to_string(),
+            status: "open".to_string(),
+            priority: 0,
+            blocked_by: blocked_by.into_iter().map(String::from).collect(),
+            blocks: blocks.into_iter().map(String::from).collect(),
+            duration: None,
+        }
+    }
+
+    fn chain_json() -> String {
+        let beads = vec![
+            bead("a", vec![], vec!["b"]),
+            bead("b", vec!["a"], vec!["c"]),
+            bead("c", vec!["b"], vec![]),
+        ];
+        serde_json::to_string(&beads).unwrap()
+    }
+
+    #[test]
+    fn test_session_ready_beads_only_a_initially() {
+        let mut session = GraphSession::new(&chain_json()).unwrap();
+        let ready: Vec<String> = serde_json::from_str(&session.ready_beads()).unwrap();
+        assert_eq!(ready, vec!["a".to_string()]);
+    }
+
+    #[test]
+    fn test_session_patch_removes_node_and_unblocks_successor() {
+        let mut session = GraphSession::new(&chain_json()).unwrap();
+        session.patch("[]", r#"["a"]"#).unwrap();
+
+        let ready: Vec<String> = serde_json::from_str(&session.ready_beads()).unwrap();
+        assert_eq!(ready, vec!["b".to_string()]);
+        assert_eq!(session.node_count(), 2);
+    }
+
+    #[test]
+    fn test_session_patch_add_edge_introducing_cycle_is_detected() {
+        let mut session = GraphSession::new(&chain_json()).unwrap();
+        assert!(!session.has_cycle());
+
+        let updated_a = bead("a", vec!["c"], vec!["b"]);
+        session.patch(&serde_json::to_string(&vec![updated_a]).unwrap(), "[]").unwrap();
+
+        assert!(session.has_cycle());
+    }
+
+    #[test]
+    fn test_session_dominators_linear_chain() {
+        let session = GraphSession::new(&chain_json()).unwrap();
+        let doms: FxHashMap<String, String> = serde_json::from_str(&session.dominators().unwrap()).unwrap();
+        assert_eq!(doms.get("c"), Some(&"b".to_string()));
+        assert_eq!(doms.get("a"), Some(&"a".to_string()));
+    }
+}
@@ -8,11 +8,14 @@
 //! - Cache-friendly iteration order
 //! - Parallel-ready execution levels
 
+use std::cell::RefCell;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 use wasm_bindgen::prelude::*;
 use petgraph::algo::toposort;
 use gastown_shared::{FxHashMap, pool::SmallBuffer};
-use crate::{BeadNode, TopoSortResult};
-use crate::dag::build_graph;
+use crate::{BeadNode, CachedSortResult, CpmScheduleResult, CpmTiming, ListScheduleResult, TopoSortResult, WorkerAssignment};
+use crate::dag::{build_graph, graph_fingerprint_internal};
 
 /// Perform topological sort on beads
 ///
@@ -39,16 +42,117 @@ pub fn topo_sort_internal(beads: &[BeadNode]) -> TopoSortResult {
             sorted: vec![],
             has_cycle: false,
             cycle_nodes: vec![],
+            cycle_groups: vec![],
         };
     }
 
     // For small graphs, use petgraph's optimized implementation
-    if beads.len() <= 100 {
-        return topo_sort_petgraph(beads);
+    let mut result = if beads.len() <= 100 {
+        topo_sort_petgraph(beads)
+    } else {
+        // For larger graphs, use our optimized Kahn's algorithm
+        topo_sort_kahn(beads)
+    };
+
+    // `cycle_nodes` doesn't say which beads form which loop; add a precise
+    // per-SCC grouping alongside it so callers can tell the loops apart.
+    if result.has_cycle {
+        result.cycle_groups = find_cycle_groups(beads);
+    }
+
+    result
+}
+
+/// Group every genuine cycle (an SCC of size > 1, or a self-loop) into its
+/// own ordered `Vec<String>` via an iterative (stack-safe) Tarjan SCC pass.
+fn find_cycle_groups(beads: &[BeadNode]) -> Vec<Vec<String>> {
+    let n = beads.len();
+    if n == 0 {
+        return vec![];
+    }
+
+    let mut id_to_index: FxHashMap<&str, usize> = FxHashMap::default();
+    id_to_index.reserve(n);
+    for (i, bead) in beads.iter().enumerate() {
+        id_to_index.insert(&bead.id, i);
+    }
+
+    let mut adj: Vec<SmallBuffer<usize, 8>> = vec![SmallBuffer::new(); n];
+    for (i, bead) in beads.iter().enumerate() {
+        for blocked in &bead.blocks {
+            if let Some(&j) = id_to_index.get(blocked.as_str()) {
+                adj[i].push(j);
+            }
+        }
+    }
+
+    let mut index = 0usize;
+    let mut indices: Vec<Option<usize>> = vec![None; n];
+    let mut lowlink: Vec<usize> = vec![0; n];
+    let mut on_stack: Vec<bool> = vec![false; n];
+    let mut stack: Vec<usize> = Vec::with_capacity(n);
+    let mut sccs: Vec<Vec<usize>> = Vec::new();
+
+    // (node, index of next successor to visit) -- explicit work stack so a
+    // deep WASM graph can't blow the call stack via recursive DFS.
+    let mut call_stack: Vec<(usize, usize)> = Vec::new();
+
+    for start in 0..n {
+        if indices[start].is_some() {
+            continue;
+        }
+
+        indices[start] = Some(index);
+        lowlink[start] = index;
+        index += 1;
+        stack.push(start);
+        on_stack[start] = true;
+        call_stack.push((start, 0));
+
+        while let Some(&(v, pos)) = call_stack.last() {
+            if pos < adj[v].len() {
+                let w = adj[v][pos];
+                call_stack.last_mut().unwrap().1 += 1;
+
+                if indices[w].is_none() {
+                    indices[w] = Some(index);
+                    lowlink[w] = index;
+                    index += 1;
+                    stack.push(w);
+                    on_stack[w] = true;
+                    call_stack.push((w, 0));
+                } else if on_stack[w] {
+                    lowlink[v] = lowlink[v].min(indices[w].unwrap());
+                }
+            } else {
+                call_stack.pop();
+
+                if let Some(&(parent, _)) = call_stack.last() {
+                    lowlink[parent] = lowlink[parent].min(lowlink[v]);
+                }
+
+                if lowlink[v] == indices[v].unwrap() {
+                    let mut scc = Vec::new();
+                    loop {
+                        let w = stack.pop().unwrap();
+                        on_stack[w] = false;
+                        scc.push(w);
+                        if w == v {
+                            break;
+                        }
+                    }
+                    sccs.push(scc);
+                }
+            }
+        }
     }
 
-    // For larger graphs, use our optimized Kahn's algorithm
-    topo_sort_kahn(beads)
+    sccs.into_iter()
+        .filter(|scc| {
+            scc.len() > 1 || (scc.len() == 1 && adj[scc[0]].contains(&scc[0]))
+        })
+        .map(|scc| scc.into_iter().map(|i| beads[i].id.clone()).collect())
+        .collect()
 }
 
 /// Topological sort using petgraph (optimized for small graphs)
@@ -66,6 +170,7 @@ fn topo_sort_petgraph(beads: &[BeadNode]) -> TopoSortResult {
                 sorted,
                 has_cycle: false,
                 cycle_nodes: vec![],
+                cycle_groups: vec![],
             }
         }
         Err(cycle) => {
@@ -75,6 +180,7 @@ fn topo_sort_petgraph(beads: &[BeadNode]) -> TopoSortResult {
                 sorted: vec![],
                 has_cycle: true,
                 cycle_nodes: vec![cycle_node],
+                cycle_groups: vec![],
             }
         }
     }
@@ -146,12 +252,14 @@ fn topo_sort_kahn(beads: &[BeadNode]) -> TopoSortResult {
             sorted: vec![],
             has_cycle: true,
             cycle_nodes,
+            cycle_groups: vec![],
         }
     } else {
         TopoSortResult {
             sorted,
             has_cycle: false,
             cycle_nodes: vec![],
+            cycle_groups: vec![],
         }
     }
 }
@@ -220,6 +328,395 @@ fn get_execution_order_internal(beads: &[BeadNode]) -> Result<Vec<Vec<String>>,
     Ok(waves)
 }
 
+/// Last computed sort + wave grouping, tagged by the fingerprint it came from
+struct CachedTopoSort {
+    fingerprint: u128,
+    result: CachedSortResult,
+}
+
+thread_local! {
+    static TOPO_SORT_CACHE: RefCell<Option<CachedTopoSort>> = RefCell::new(None);
+}
+
+/// Sort and wave-group beads, memoized against a 128-bit structural
+/// fingerprint of the graph (see `dag::graph_fingerprint_internal`) so
+/// repeated calls on an unchanged graph skip the full O(V+E) pass.
+///
+/// If `previous_fingerprint` matches what's cached, the rehash itself is
+/// skipped entirely -- useful when the host already knows nothing changed.
+#[inline]
+pub fn topo_sort_cached_impl(beads_json: &str, previous_fingerprint: Option<String>) -> Result<String, JsValue> {
+    if let Some(prev) = previous_fingerprint.as_deref().and_then(|hex| u128::from_str_radix(hex, 16).ok()) {
+        if let Some(cached) = cached_result_for(prev) {
+            return serde_json::to_string(&cached)
+                .map_err(|e| JsValue::from_str(&format!("Serialize error: {}", e)));
+        }
+    }
+
+    let beads: Vec<BeadNode> = serde_json::from_str(beads_json)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+    let fingerprint = graph_fingerprint_internal(&beads, false);
+
+    if let Some(cached) = cached_result_for(fingerprint) {
+        return serde_json::to_string(&cached)
+            .map_err(|e| JsValue::from_str(&format!("Serialize error: {}", e)));
+    }
+
+    let sort = topo_sort_internal(&beads);
+    let waves = if sort.has_cycle { vec![] } else { get_execution_order_internal(&beads)? };
+
+    let result = CachedSortResult {
+        fingerprint: format!("{:032x}", fingerprint),
+        sort,
+        waves,
+    };
+
+    TOPO_SORT_CACHE.with(|cache| {
+        *cache.borrow_mut() = Some(CachedTopoSort { fingerprint, result: result.clone() });
+    });
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Serialize error: {}", e)))
+}
+
+/// Return a clone of the cached result if its fingerprint matches
+fn cached_result_for(fingerprint: u128) -> Option<CachedSortResult> {
+    TOPO_SORT_CACHE.with(|cache| {
+        cache.borrow()
+            .as_ref()
+            .filter(|cached| cached.fingerprint == fingerprint)
+            .map(|cached| cached.result.clone())
+    })
+}
+
+/// Run the Critical Path Method, returning full four-point timing for every bead
+///
+/// Missing `duration` is treated as 0. Bails with an error if the dependency
+/// graph contains a cycle, since CPM requires a valid topological order.
+#[inline]
+pub fn cpm_schedule_impl(beads_json: &str) -> Result<String, JsValue> {
+    let beads: Vec<BeadNode> = serde_json::from_str(beads_json)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+    let result = cpm_schedule_internal(&beads)?;
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Serialize error: {}", e)))
+}
+
+/// Internal CPM implementation: forward pass for ES/EF, backward pass for LS/LF
+#[inline]
+fn cpm_schedule_internal(beads: &[BeadNode]) -> Result<CpmScheduleResult, JsValue> {
+    if beads.is_empty() {
+        return Ok(CpmScheduleResult {
+            makespan: 0,
+            per_bead: vec![],
+            critical_path: vec![],
+        });
+    }
+
+    let topo = topo_sort_internal(beads);
+    if topo.has_cycle {
+        return Err(JsValue::from_str("Cannot compute CPM schedule: cycle detected"));
+    }
+
+    let n = beads.len();
+    let mut id_to_index: FxHashMap<&str, usize> = FxHashMap::default();
+    id_to_index.reserve(n);
+    for (i, bead) in beads.iter().enumerate() {
+        id_to_index.insert(&bead.id, i);
+    }
+
+    let duration = |i: usize| beads[i].duration.unwrap_or(0);
+
+    // Forward pass in topological order: ES[i] = max(EF[p]) over predecessors
+    let mut es = vec![0u32; n];
+    let mut ef = vec![0u32; n];
+    for id in &topo.sorted {
+        let i = id_to_index[id.as_str()];
+        let start = beads[i].blocked_by.iter()
+            .filter_map(|p| id_to_index.get(p.as_str()))
+            .map(|&p| ef[p])
+            .max()
+            .unwrap_or(0);
+        es[i] = start;
+        ef[i] = start + duration(i);
+    }
+
+    let makespan = ef.iter().copied().max().unwrap_or(0);
+
+    // Backward pass in reverse topological order: LF[i] = min(LS[s]) over successors
+    let mut ls = vec![0u32; n];
+    let mut lf = vec![0u32; n];
+    for id in topo.sorted.iter().rev() {
+        let i = id_to_index[id.as_str()];
+        let finish = beads[i].blocks.iter()
+            .filter_map(|s| id_to_index.get(s.as_str()))
+            .map(|&s| ls[s])
+            .min()
+            .unwrap_or(makespan);
+        lf[i] = finish;
+        // `blocks`/`blocked_by` come from unvalidated external JSON and
+        // aren't required to be reciprocal, so a non-reciprocal edge can
+        // pull `finish` below `duration(i)`; saturate rather than panic.
+        ls[i] = finish.saturating_sub(duration(i));
+    }
+
+    let per_bead: Vec<CpmTiming> = topo.sorted.iter()
+        .map(|id| {
+            let i = id_to_index[id.as_str()];
+            CpmTiming {
+                id: id.clone(),
+                es: es[i],
+                ef: ef[i],
+                ls: ls[i],
+                lf: lf[i],
+                slack: ls[i].saturating_sub(es[i]),
+            }
+        })
+        .collect();
+
+    let critical_path: Vec<String> = per_bead.iter()
+        .filter(|t| t.slack == 0)
+        .map(|t| t.id.clone())
+        .collect();
+
+    Ok(CpmScheduleResult {
+        makespan,
+        per_bead,
+        critical_path,
+    })
+}
+
+/// Fixed-width bitset for reachability tracking, keyed by `id_to_index`
+#[derive(Clone)]
+struct Bitset {
+    words: Vec<u64>,
+}
+
+impl Bitset {
+    fn new(n: usize) -> Self {
+        Self { words: vec![0u64; n.div_ceil(64).max(1)] }
+    }
+
+    #[inline(always)]
+    fn set(&mut self, i: usize) {
+        self.words[i / 64] |= 1u64 << (i % 64);
+    }
+
+    #[inline(always)]
+    fn get(&self, i: usize) -> bool {
+        (self.words[i / 64] >> (i % 64)) & 1 == 1
+    }
+
+    fn union_with(&mut self, other: &Bitset) {
+        for (a, b) in self.words.iter_mut().zip(&other.words) {
+            *a |= b;
+        }
+    }
+}
+
+/// Compute the transitive reduction of the bead dependency graph, returning a
+/// new bead set with redundant `blocks`/`blocked_by` edges stripped
+#[inline]
+pub fn transitive_reduction_impl(beads_json: &str) -> Result<String, JsValue> {
+    let beads: Vec<BeadNode> = serde_json::from_str(beads_json)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+    let result = transitive_reduction_internal(&beads)?;
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Serialize error: {}", e)))
+}
+
+/// Strip any direct edge `u -> v` that is already implied by some other path
+/// from `u` to `v`, using a per-node reachability bitset built in reverse
+/// topological order.
+fn transitive_reduction_internal(beads: &[BeadNode]) -> Result<Vec<BeadNode>, JsValue> {
+    let n = beads.len();
+    if n == 0 {
+        return Ok(vec![]);
+    }
+
+    let topo = topo_sort_internal(beads);
+    if topo.has_cycle {
+        return Err(JsValue::from_str("Cannot compute transitive reduction: cycle detected"));
+    }
+
+    let mut id_to_index: FxHashMap<&str, usize> = FxHashMap::default();
+    id_to_index.reserve(n);
+    for (i, bead) in beads.iter().enumerate() {
+        id_to_index.insert(&bead.id, i);
+    }
+
+    let mut successors: Vec<SmallBuffer<usize, 8>> = vec![SmallBuffer::new(); n];
+    for (i, bead) in beads.iter().enumerate() {
+        for blocked in &bead.blocks {
+            if let Some(&j) = id_to_index.get(blocked.as_str()) {
+                successors[i].push(j);
+            }
+        }
+    }
+
+    // reach[u] = union of reach[succ] ∪ {succ} over direct successors, built
+    // in reverse topological order so every successor is already finalized.
+    let mut reach: Vec<Bitset> = vec![Bitset::new(n); n];
+    for id in topo.sorted.iter().rev() {
+        let u = id_to_index[id.as_str()];
+        for &v in &successors[u] {
+            reach[u].set(v);
+            let succ_reach = reach[v].clone();
+            reach[u].union_with(&succ_reach);
+        }
+    }
+
+    let mut kept_successors: Vec<Vec<usize>> = vec![Vec::new(); n];
+    for (u, succs) in successors.iter().enumerate() {
+        for &v in succs {
+            let redundant = succs.iter().any(|&w| w != v && reach[w].get(v));
+            if !redundant {
+                kept_successors[u].push(v);
+            }
+        }
+    }
+
+    let mut new_blocks: Vec<Vec<String>> = vec![Vec::new(); n];
+    let mut new_blocked_by: Vec<Vec<String>> = vec![Vec::new(); n];
+    for (u, succs) in kept_successors.iter().enumerate() {
+        for &v in succs {
+            new_blocks[u].push(beads[v].id.clone());
+            new_blocked_by[v].push(beads[u].id.clone());
+        }
+    }
+
+    Ok(beads.iter().enumerate().map(|(i, bead)| BeadNode {
+        id: bead.id.clone(),
+        title: bead.title.clone(),
+        status: bead.status.clone(),
+        priority: bead.priority,
+        blocked_by: new_blocked_by[i].clone(),
+        blocks: new_blocks[i].clone(),
+        duration: bead.duration,
+    }).collect())
+}
+
+/// Schedule beads onto `max_workers` workers via list scheduling
+#[inline]
+pub fn list_schedule_impl(beads_json: &str, max_workers: u32) -> Result<String, JsValue> {
+    let beads: Vec<BeadNode> = serde_json::from_str(beads_json)
+        .map_err(|e| JsValue::from_str(&format!("Parse error: {}", e)))?;
+
+    let result = list_schedule_internal(&beads, max_workers.max(1) as usize)?;
+
+    serde_json::to_string(&result)
+        .map_err(|e| JsValue::from_str(&format!("Serialize error: {}", e)))
+}
+
+/// List-scheduling simulation bounded to `max_workers` concurrent beads,
+/// prioritizing by upward rank (duration plus the longest remaining chain of
+/// work through successors), ties broken by descending `priority`
+fn list_schedule_internal(beads: &[BeadNode], max_workers: usize) -> Result<ListScheduleResult, JsValue> {
+    if beads.is_empty() {
+        return Ok(ListScheduleResult { makespan: 0, assignments: vec![] });
+    }
+
+    let topo = topo_sort_internal(beads);
+    if topo.has_cycle {
+        return Err(JsValue::from_str("Cannot compute list schedule: cycle detected"));
+    }
+
+    let n = beads.len();
+    let mut id_to_index: FxHashMap<&str, usize> = FxHashMap::default();
+    id_to_index.reserve(n);
+    for (i, bead) in beads.iter().enumerate() {
+        id_to_index.insert(&bead.id, i);
+    }
+
+    let mut successors: Vec<SmallBuffer<usize, 8>> = vec![SmallBuffer::new(); n];
+    let mut remaining: Vec<usize> = beads.iter().map(|b| b.blocked_by.len()).collect();
+    for (i, bead) in beads.iter().enumerate() {
+        for blocked in &bead.blocks {
+            if let Some(&j) = id_to_index.get(blocked.as_str()) {
+                successors[i].push(j);
+            }
+        }
+    }
+
+    let durations: Vec<u32> = beads.iter().map(|b| b.duration.unwrap_or(0)).collect();
+
+    // Upward rank via a reverse topological pass: own duration plus the max
+    // rank among direct successors.
+    let mut rank: Vec<u32> = vec![0; n];
+    for id in topo.sorted.iter().rev() {
+        let i = id_to_index[id.as_str()];
+        let max_succ_rank = successors[i].iter().map(|&s| rank[s]).max().unwrap_or(0);
+        rank[i] = durations[i] + max_succ_rank;
+    }
+
+    let mut ready: Vec<usize> = (0..n).filter(|&i| remaining[i] == 0).collect();
+    let mut free_workers: BinaryHeap<Reverse<usize>> = (0..max_workers).map(Reverse).collect();
+    let mut busy: BinaryHeap<Reverse<(u32, usize, usize)>> = BinaryHeap::new(); // (finish, worker, bead_index)
+    let mut clock: u32 = 0;
+    let mut scheduled = 0usize;
+    let mut assignments: Vec<WorkerAssignment> = Vec::with_capacity(n);
+
+    while scheduled < n {
+        // Descending upward rank first, ties broken by descending priority
+        ready.sort_by(|&a, &b| {
+            rank[b].cmp(&rank[a]).then(beads[b].priority.cmp(&beads[a].priority))
+        });
+
+        while let (Some(&Reverse(worker)), false) = (free_workers.peek(), ready.is_empty()) {
+            free_workers.pop();
+            let i = ready.remove(0);
+
+            let start = clock;
+            let finish = start + durations[i];
+            assignments.push(WorkerAssignment {
+                id: beads[i].id.clone(),
+                worker: worker as u32,
+                start,
+                finish,
+            });
+            busy.push(Reverse((finish, worker, i)));
+            scheduled += 1;
+        }
+
+        if scheduled == n {
+            break;
+        }
+
+        // No free worker or nothing ready yet: advance the clock to the next
+        // finish event and free that worker (and any others finishing at the
+        // same instant).
+        if let Some(&Reverse((next_finish, _, _))) = busy.peek() {
+            clock = clock.max(next_finish);
+            while let Some(&Reverse((f, w, bead_i))) = busy.peek() {
+                if f > clock {
+                    break;
+                }
+                busy.pop();
+                free_workers.push(Reverse(w));
+                for &s in &successors[bead_i] {
+                    remaining[s] -= 1;
+                    if remaining[s] == 0 {
+                        ready.push(s);
+                    }
+                }
+            }
+        } else {
+            // Nothing busy and nothing ready: dependency data is inconsistent.
+            // Bail rather than spin forever.
+            break;
+        }
+    }
+
+    let makespan = assignments.iter().map(|a| a.finish).max().unwrap_or(0);
+
+    Ok(ListScheduleResult { makespan, assignments })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -390,4 +887,477 @@ mod tests {
         assert!(result.has_cycle);
         assert!(!result.cycle_nodes.is_empty());
     }
+
+    #[test]
+    fn test_cpm_schedule_linear_chain_has_no_slack() {
+        let beads = vec![
+            BeadNode {
+                id: "a".to_string(),
+                title: "A".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec![],
+                blocks: vec!["b".to_string()],
+                duration: Some(10),
+            },
+            BeadNode {
+                id: "b".to_string(),
+                title: "B".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec!["a".to_string()],
+                blocks: vec!["c".to_string()],
+                duration: Some(20),
+            },
+            BeadNode {
+                id: "c".to_string(),
+                title: "C".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec!["b".to_string()],
+                blocks: vec![],
+                duration: Some(15),
+            },
+        ];
+
+        let result = cpm_schedule_internal(&beads).unwrap();
+
+        assert_eq!(result.makespan, 45);
+        assert_eq!(result.critical_path, vec!["a", "b", "c"]);
+        for timing in &result.per_bead {
+            assert_eq!(timing.slack, 0);
+        }
+    }
+
+    #[test]
+    fn test_cpm_schedule_reports_slack_on_short_branch() {
+        // a -> b -> d (long path), a -> c -> d (short path with slack)
+        let beads = vec![
+            BeadNode {
+                id: "a".to_string(),
+                title: "A".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec![],
+                blocks: vec!["b".to_string(), "c".to_string()],
+                duration: Some(0),
+            },
+            BeadNode {
+                id: "b".to_string(),
+                title: "B".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec!["a".to_string()],
+                blocks: vec!["d".to_string()],
+                duration: Some(10),
+            },
+            BeadNode {
+                id: "c".to_string(),
+                title: "C".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec!["a".to_string()],
+                blocks: vec!["d".to_string()],
+                duration: Some(2),
+            },
+            BeadNode {
+                id: "d".to_string(),
+                title: "D".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec!["b".to_string(), "c".to_string()],
+                blocks: vec![],
+                duration: Some(5),
+            },
+        ];
+
+        let result = cpm_schedule_internal(&beads).unwrap();
+
+        assert_eq!(result.makespan, 15);
+        assert_eq!(result.critical_path, vec!["a", "b", "d"]);
+
+        let c_timing = result.per_bead.iter().find(|t| t.id == "c").unwrap();
+        assert_eq!(c_timing.slack, 8);
+    }
+
+    #[test]
+    fn test_cpm_schedule_rejects_cycle() {
+        let beads = vec![
+            BeadNode {
+                id: "a".to_string(),
+                title: "A".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec!["b".to_string()],
+                blocks: vec!["b".to_string()],
+                duration: Some(1),
+            },
+            BeadNode {
+                id: "b".to_string(),
+                title: "B".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec!["a".to_string()],
+                blocks: vec!["a".to_string()],
+                duration: Some(1),
+            },
+        ];
+
+        assert!(cpm_schedule_internal(&beads).is_err());
+    }
+
+    #[test]
+    fn test_topo_sort_cycle_groups_are_distinct_per_loop() {
+        // Two disjoint cycles: a <-> b, and c -> d -> e -> c
+        let beads = vec![
+            BeadNode {
+                id: "a".to_string(),
+                title: "A".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec!["b".to_string()],
+                blocks: vec!["b".to_string()],
+                duration: None,
+            },
+            BeadNode {
+                id: "b".to_string(),
+                title: "B".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec!["a".to_string()],
+                blocks: vec!["a".to_string()],
+                duration: None,
+            },
+            BeadNode {
+                id: "c".to_string(),
+                title: "C".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec!["e".to_string()],
+                blocks: vec!["d".to_string()],
+                duration: None,
+            },
+            BeadNode {
+                id: "d".to_string(),
+                title: "D".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec!["c".to_string()],
+                blocks: vec!["e".to_string()],
+                duration: None,
+            },
+            BeadNode {
+                id: "e".to_string(),
+                title: "E".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec!["d".to_string()],
+                blocks: vec!["c".to_string()],
+                duration: None,
+            },
+        ];
+
+        let result = topo_sort_internal(&beads);
+
+        assert!(result.has_cycle);
+        assert_eq!(result.cycle_groups.len(), 2);
+
+        let mut sizes: Vec<usize> = result.cycle_groups.iter().map(|g| g.len()).collect();
+        sizes.sort_unstable();
+        assert_eq!(sizes, vec![2, 3]);
+    }
+
+    #[test]
+    fn test_topo_sort_cycle_groups_detect_self_loop() {
+        let beads = vec![BeadNode {
+            id: "a".to_string(),
+            title: "A".to_string(),
+            status: "open".to_string(),
+            priority: 0,
+            blocked_by: vec!["a".to_string()],
+            blocks: vec!["a".to_string()],
+            duration: None,
+        }];
+
+        let result = topo_sort_internal(&beads);
+
+        assert!(result.has_cycle);
+        assert_eq!(result.cycle_groups, vec![vec!["a".to_string()]]);
+    }
+
+    #[test]
+    fn test_transitive_reduction_drops_redundant_shortcut() {
+        // a -> b -> c, plus a stale shortcut a -> c
+        let beads = vec![
+            BeadNode {
+                id: "a".to_string(),
+                title: "A".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec![],
+                blocks: vec!["b".to_string(), "c".to_string()],
+                duration: None,
+            },
+            BeadNode {
+                id: "b".to_string(),
+                title: "B".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec!["a".to_string()],
+                blocks: vec!["c".to_string()],
+                duration: None,
+            },
+            BeadNode {
+                id: "c".to_string(),
+                title: "C".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec!["a".to_string(), "b".to_string()],
+                blocks: vec![],
+                duration: None,
+            },
+        ];
+
+        let reduced = transitive_reduction_internal(&beads).unwrap();
+
+        let a = reduced.iter().find(|b| b.id == "a").unwrap();
+        assert_eq!(a.blocks, vec!["b".to_string()]);
+
+        let c = reduced.iter().find(|b| b.id == "c").unwrap();
+        assert_eq!(c.blocked_by, vec!["b".to_string()]);
+    }
+
+    #[test]
+    fn test_transitive_reduction_keeps_diamond_intact() {
+        // a -> b, a -> c, b -> d, c -> d: no edge is redundant here
+        let beads = vec![
+            BeadNode {
+                id: "a".to_string(),
+                title: "A".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec![],
+                blocks: vec!["b".to_string(), "c".to_string()],
+                duration: None,
+            },
+            BeadNode {
+                id: "b".to_string(),
+                title: "B".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec!["a".to_string()],
+                blocks: vec!["d".to_string()],
+                duration: None,
+            },
+            BeadNode {
+                id: "c".to_string(),
+                title: "C".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec!["a".to_string()],
+                blocks: vec!["d".to_string()],
+                duration: None,
+            },
+            BeadNode {
+                id: "d".to_string(),
+                title: "D".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec!["b".to_string(), "c".to_string()],
+                blocks: vec![],
+                duration: None,
+            },
+        ];
+
+        let reduced = transitive_reduction_internal(&beads).unwrap();
+
+        let a = reduced.iter().find(|b| b.id == "a").unwrap();
+        let mut a_blocks = a.blocks.clone();
+        a_blocks.sort();
+        assert_eq!(a_blocks, vec!["b".to_string(), "c".to_string()]);
+    }
+
+    #[test]
+    fn test_list_schedule_single_worker_is_fully_serial() {
+        let beads = vec![
+            BeadNode {
+                id: "a".to_string(),
+                title: "A".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec![],
+                blocks: vec![],
+                duration: Some(5),
+            },
+            BeadNode {
+                id: "b".to_string(),
+                title: "B".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec![],
+                blocks: vec![],
+                duration: Some(3),
+            },
+        ];
+
+        let result = list_schedule_internal(&beads, 1).unwrap();
+
+        assert_eq!(result.assignments.len(), 2);
+        assert_eq!(result.makespan, 8);
+        assert!(result.assignments.iter().all(|a| a.worker == 0));
+    }
+
+    #[test]
+    fn test_list_schedule_two_workers_run_independent_beads_in_parallel() {
+        let beads = vec![
+            BeadNode {
+                id: "a".to_string(),
+                title: "A".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec![],
+                blocks: vec![],
+                duration: Some(10),
+            },
+            BeadNode {
+                id: "b".to_string(),
+                title: "B".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec![],
+                blocks: vec![],
+                duration: Some(5),
+            },
+        ];
+
+        let result = list_schedule_internal(&beads, 2).unwrap();
+
+        assert_eq!(result.makespan, 10);
+        let workers: std::collections::HashSet<u32> =
+            result.assignments.iter().map(|a| a.worker).collect();
+        assert_eq!(workers.len(), 2);
+    }
+
+    #[test]
+    fn test_list_schedule_prioritizes_higher_upward_rank_first() {
+        // a blocks a long bead b, d is an independent short bead.
+        // With one worker, a should run before d despite d having no
+        // dependents, because a's upward rank is higher.
+        let beads = vec![
+            BeadNode {
+                id: "a".to_string(),
+                title: "A".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec![],
+                blocks: vec!["b".to_string()],
+                duration: Some(1),
+            },
+            BeadNode {
+                id: "b".to_string(),
+                title: "B".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec!["a".to_string()],
+                blocks: vec![],
+                duration: Some(20),
+            },
+            BeadNode {
+                id: "d".to_string(),
+                title: "D".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec![],
+                blocks: vec![],
+                duration: Some(1),
+            },
+        ];
+
+        let result = list_schedule_internal(&beads, 1).unwrap();
+
+        let a_start = result.assignments.iter().find(|x| x.id == "a").unwrap().start;
+        let d_start = result.assignments.iter().find(|x| x.id == "d").unwrap().start;
+        assert!(a_start < d_start);
+    }
+
+    fn linear_chain() -> Vec<BeadNode> {
+        vec![
+            BeadNode {
+                id: "a".to_string(),
+                title: "A".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec![],
+                blocks: vec!["b".to_string()],
+                duration: None,
+            },
+            BeadNode {
+                id: "b".to_string(),
+                title: "B".to_string(),
+                status: "open".to_string(),
+                priority: 0,
+                blocked_by: vec!["a".to_string()],
+                blocks: vec![],
+                duration: None,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_topo_sort_cached_hits_on_unchanged_graph() {
+        let beads = linear_chain();
+        let beads_json = serde_json::to_string(&beads).unwrap();
+
+        let first = topo_sort_cached_impl(&beads_json, None).unwrap();
+        let second = topo_sort_cached_impl(&beads_json, None).unwrap();
+
+        let first: CachedSortResult = serde_json::from_str(&first).unwrap();
+        let second: CachedSortResult = serde_json::from_str(&second).unwrap();
+
+        assert_eq!(first.fingerprint, second.fingerprint);
+        assert_eq!(first.sort.sorted, vec!["a".to_string(), "b".to_string()]);
+        assert_eq!(second.waves.len(), 2);
+    }
+
+    #[test]
+    fn test_topo_sort_cached_recomputes_when_fingerprint_differs() {
+        let beads = linear_chain();
+        let beads_json = serde_json::to_string(&beads).unwrap();
+        let first = topo_sort_cached_impl(&beads_json, None).unwrap();
+        let first: CachedSortResult = serde_json::from_str(&first).unwrap();
+
+        let mut changed = beads.clone();
+        changed.push(BeadNode {
+            id: "c".to_string(),
+            title: "C".to_string(),
+            status: "open".to_string(),
+            priority: 0,
+            blocked_by: vec!["b".to_string()],
+            blocks: vec![],
+            duration: None,
+        });
+        let changed_json = serde_json::to_string(&changed).unwrap();
+        let second = topo_sort_cached_impl(&changed_json, None).unwrap();
+        let second: CachedSortResult = serde_json::from_str(&second).unwrap();
+
+        assert_ne!(first.fingerprint, second.fingerprint);
+        assert_eq!(second.sort.sorted.len(), 3);
+    }
+
+    #[test]
+    fn test_topo_sort_cached_skips_rehash_with_matching_previous_fingerprint() {
+        let beads = linear_chain();
+        let beads_json = serde_json::to_string(&beads).unwrap();
+
+        let first = topo_sort_cached_impl(&beads_json, None).unwrap();
+        let first: CachedSortResult = serde_json::from_str(&first).unwrap();
+
+        // Pass garbage JSON: if the host-supplied fingerprint short-circuits
+        // the rehash, the garbage is never parsed and this still succeeds.
+        let second = topo_sort_cached_impl("not valid json", Some(first.fingerprint.clone())).unwrap();
+        let second: CachedSortResult = serde_json::from_str(&second).unwrap();
+
+        assert_eq!(first.fingerprint, second.fingerprint);
+        assert_eq!(first.sort.sorted, second.sort.sorted);
+    }
 }
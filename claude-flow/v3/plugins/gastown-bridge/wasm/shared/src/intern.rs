@@ -4,7 +4,7 @@
 //! Interned strings can be compared by pointer equality (O(1)).
 
 use super::hash::{FxHashMap, fx_hash_str};
-use std::cell::RefCell;
+use std::cell::{RefCell, UnsafeCell};
 
 /// An interned string symbol (cheap to copy and compare)
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -16,11 +16,28 @@ impl Symbol {
     pub fn index(self) -> u32 {
         self.0
     }
+
+    /// Reconstruct a symbol from a previously-observed index
+    ///
+    /// Callers that store raw `u32` indices (e.g. in a compact adjacency
+    /// representation) use this to turn them back into `Symbol`s for lookup.
+    #[inline(always)]
+    pub fn from_index(index: u32) -> Self {
+        Symbol(index)
+    }
 }
 
 /// String interner for zero-copy string deduplication
+///
+/// Backing storage is an append-only arena of `Box<str>` entries. Growing
+/// the arena only ever relocates the small `(ptr, len)` fat pointers held in
+/// the outer `Vec`; it never touches the heap bytes an existing `Box<str>`
+/// points to, and an entry is never replaced once pushed; only `clear`
+/// removes entries. So the `&str` that `resolve` returns stays valid,
+/// independent of later `intern` calls, until the interner is dropped or
+/// `clear` is called (see `clear`).
 pub struct StringInterner {
-    strings: RefCell<Vec<String>>,
+    strings: UnsafeCell<Vec<Box<str>>>,
     map: RefCell<FxHashMap<u64, Symbol>>,
 }
 
@@ -29,7 +46,7 @@ impl StringInterner {
     #[inline]
     pub fn new() -> Self {
         Self {
-            strings: RefCell::new(Vec::with_capacity(64)),
+            strings: UnsafeCell::new(Vec::with_capacity(64)),
             map: RefCell::new(FxHashMap::default()),
         }
     }
@@ -38,7 +55,7 @@ impl StringInterner {
     #[inline]
     pub fn with_capacity(capacity: usize) -> Self {
         Self {
-            strings: RefCell::new(Vec::with_capacity(capacity)),
+            strings: UnsafeCell::new(Vec::with_capacity(capacity)),
             map: RefCell::new(FxHashMap::default()),
         }
     }
@@ -53,10 +70,16 @@ impl StringInterner {
             return symbol;
         }
 
-        // Intern the string
-        let mut strings = self.strings.borrow_mut();
-        let index = strings.len() as u32;
-        strings.push(s.to_string());
+        // SAFETY: appending never moves or drops an existing entry's heap
+        // allocation (see struct docs), so this momentary `&mut` can't
+        // invalidate any `&str` a caller is holding from an earlier
+        // `resolve`/`get_str` call.
+        let index = unsafe {
+            let strings = &mut *self.strings.get();
+            let index = strings.len() as u32;
+            strings.push(s.into());
+            index
+        };
 
         let symbol = Symbol(index);
         self.map.borrow_mut().insert(hash, symbol);
@@ -67,42 +90,49 @@ impl StringInterner {
     /// Get the string for a symbol
     #[inline(always)]
     pub fn get(&self, symbol: Symbol) -> Option<String> {
-        self.strings
-            .borrow()
-            .get(symbol.0 as usize)
-            .cloned()
+        self.resolve(symbol).map(str::to_string)
     }
 
-    /// Get the string slice for a symbol (borrows interner)
+    /// Get the string slice for a symbol, borrowed from the interner's arena
     #[inline(always)]
-    pub fn get_str(&self, symbol: Symbol) -> Option<&str> {
-        // Safety: We never remove strings, so the reference is valid
-        // This is a workaround for the borrow checker
+    pub fn resolve(&self, symbol: Symbol) -> Option<&str> {
+        // SAFETY: see struct docs -- entries are append-only and never
+        // moved/dropped, so this reference is valid for as long as `self`.
         unsafe {
-            self.strings
-                .as_ptr()
-                .as_ref()
-                .and_then(|s| s.get(symbol.0 as usize))
-                .map(|s| s.as_str())
+            (*self.strings.get())
+                .get(symbol.0 as usize)
+                .map(|s| s.as_ref())
         }
     }
 
+    /// Get the string slice for a symbol (alias for [`StringInterner::resolve`])
+    #[inline(always)]
+    pub fn get_str(&self, symbol: Symbol) -> Option<&str> {
+        self.resolve(symbol)
+    }
+
     /// Number of interned strings
     #[inline]
     pub fn len(&self) -> usize {
-        self.strings.borrow().len()
+        // SAFETY: shared read of the current length; no outstanding `&mut` exists.
+        unsafe { (*self.strings.get()).len() }
     }
 
     /// Check if the interner is empty
     #[inline]
     pub fn is_empty(&self) -> bool {
-        self.strings.borrow().is_empty()
+        self.len() == 0
     }
 
     /// Clear all interned strings
+    ///
+    /// Invalidates any `&str` previously handed out by `resolve`/`get_str`;
+    /// callers must not hold onto those references across a `clear`.
     #[inline]
     pub fn clear(&self) {
-        self.strings.borrow_mut().clear();
+        // SAFETY: no outstanding borrows are allowed to survive a `clear` per
+        // the method's documented contract.
+        unsafe { (*self.strings.get()).clear(); }
         self.map.borrow_mut().clear();
     }
 }
@@ -142,6 +172,30 @@ mod tests {
         assert_eq!(interner.get(symbol), Some("test".to_string()));
     }
 
+    #[test]
+    fn test_resolve_matches_get() {
+        let interner = StringInterner::new();
+        let symbol = interner.intern("test");
+
+        assert_eq!(interner.resolve(symbol), Some("test"));
+        assert_eq!(interner.get_str(symbol), Some("test"));
+    }
+
+    #[test]
+    fn test_resolve_stays_valid_across_further_interning() {
+        let interner = StringInterner::new();
+        let first = interner.intern("first");
+
+        let borrowed = interner.resolve(first).unwrap();
+        // Interning many more strings may reallocate the backing arena; the
+        // borrow into the first entry must remain valid regardless.
+        for i in 0..256 {
+            interner.intern(&format!("more-{i}"));
+        }
+
+        assert_eq!(borrowed, "first");
+    }
+
     #[test]
     fn test_intern_batch() {
         let interner = StringInterner::new();